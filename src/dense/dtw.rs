@@ -0,0 +1,363 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Dynamic time warping between dense vectors.
+//!
+//! Unlike `Distance` dynamic time warping treats vectors as time series,
+//! aligning their components non-linearly, and supports vectors of differing lengths.
+
+use std::collections::VecDeque;
+use std::mem;
+
+use num_traits::Float;
+
+/// Dynamic time warping, with an optional sakoe-chiba band
+///
+/// Uses the squared difference between aligned components as local cost.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Dtw {
+    window: Option<usize>,
+}
+
+impl Dtw {
+    /// Creates an unconstrained `Dtw`.
+    pub fn new() -> Self {
+        Self { window: None }
+    }
+
+    /// Constrains alignments to a sakoe-chiba band of radius `window`,
+    /// i.e. to pairs of components whose indices differ by at most `window`.
+    ///
+    /// The band is widened to the difference in length between two vectors,
+    /// as otherwise no alignment would exist.
+    pub fn with_window(self, window: usize) -> Self {
+        Self { window: Some(window) }
+    }
+
+    /// The radius of the sakoe-chiba band, if constrained
+    pub fn window(&self) -> Option<usize> {
+        self.window
+    }
+
+    /// Calculates the squared dynamic time warping distance between `lhs` and `rhs`.
+    ///
+    /// Is infinite if exactly one of `lhs` and `rhs` is empty.
+    pub fn squared_distance<T, L, R>(&self, lhs: &L, rhs: &R) -> T
+    where
+        T: Float,
+        L: AsRef<[T]> + ?Sized,
+        R: AsRef<[T]> + ?Sized,
+    {
+        let distance = self.squared_distance_bounded(lhs, rhs, T::infinity());
+        distance.unwrap_or_else(T::infinity)
+    }
+
+    /// Calculates the dynamic time warping distance between `lhs` and `rhs`.
+    ///
+    /// Is infinite if exactly one of `lhs` and `rhs` is empty.
+    pub fn distance<T, L, R>(&self, lhs: &L, rhs: &R) -> T
+    where
+        T: Float,
+        L: AsRef<[T]> + ?Sized,
+        R: AsRef<[T]> + ?Sized,
+    {
+        self.squared_distance(lhs, rhs).sqrt()
+    }
+
+    /// Calculates the squared dynamic time warping distance between `lhs` and `rhs`,
+    /// if it does not exceed `bound`.
+    ///
+    /// Abandons the calculation as soon as every partial alignment exceeds `bound`.
+    pub fn squared_distance_bounded<T, L, R>(&self, lhs: &L, rhs: &R, bound: T) -> Option<T>
+    where
+        T: Float,
+        L: AsRef<[T]> + ?Sized,
+        R: AsRef<[T]> + ?Sized,
+    {
+        let (lhs, rhs) = (lhs.as_ref(), rhs.as_ref());
+        let (lhs_len, rhs_len) = (lhs.len(), rhs.len());
+        if lhs_len == 0 || rhs_len == 0 {
+            let distance = if lhs_len == rhs_len { T::zero() } else { T::infinity() };
+            return if distance > bound { None } else { Some(distance) };
+        }
+        let difference = lhs_len.abs_diff(rhs_len);
+        let window = self.window.map_or(lhs_len.max(rhs_len), |window| window.max(difference));
+        // `prev[j]` and `curr[j]` hold the cost of aligning a prefix of `lhs`
+        // with the first `j` components of `rhs`:
+        let mut prev = vec![T::infinity(); rhs_len + 1];
+        let mut curr = vec![T::infinity(); rhs_len + 1];
+        prev[0] = T::zero();
+        for (i, &lhs) in lhs.iter().enumerate().map(|(i, lhs)| (i + 1, lhs)) {
+            let start = i.saturating_sub(window).max(1);
+            let end = i.saturating_add(window).min(rhs_len);
+            // This and the next row only read the band and its boundary cells,
+            // so any costs left over from earlier rows only need resetting there:
+            for cost in &mut curr[(start - 1)..(end + 2).min(rhs_len + 1)] {
+                *cost = T::infinity();
+            }
+            let mut min = T::infinity();
+            for j in start..(end + 1) {
+                let delta = lhs - rhs[j - 1];
+                let cost = (delta * delta) + prev[j - 1].min(prev[j]).min(curr[j - 1]);
+                curr[j] = cost;
+                min = min.min(cost);
+            }
+            if min > bound {
+                return None;
+            }
+            mem::swap(&mut prev, &mut curr);
+        }
+        let distance = prev[rhs_len];
+        if distance > bound { None } else { Some(distance) }
+    }
+
+    /// Calculates the dynamic time warping distance between `lhs` and `rhs`,
+    /// if it does not exceed `bound`.
+    ///
+    /// Returns `None` for a negative `bound`, which no distance can satisfy.
+    pub fn distance_bounded<T, L, R>(&self, lhs: &L, rhs: &R, bound: T) -> Option<T>
+    where
+        T: Float,
+        L: AsRef<[T]> + ?Sized,
+        R: AsRef<[T]> + ?Sized,
+    {
+        if bound < T::zero() {
+            return None;
+        }
+        self.squared_distance_bounded(lhs, rhs, bound * bound).map(T::sqrt)
+    }
+
+    /// Calculates the envelope of `query` within `self`'s window.
+    ///
+    /// Calculating the envelope once per query makes each subsequent
+    /// `Envelope::lb_keogh` linear in the length of the candidate.
+    pub fn envelope<T, V>(&self, query: &V) -> Envelope<T>
+    where
+        T: Float,
+        V: AsRef<[T]> + ?Sized,
+    {
+        let query = query.as_ref();
+        let window = self.window.unwrap_or(query.len());
+        Envelope {
+            lower: sliding_extremum(query, window, |value, other| value <= other),
+            upper: sliding_extremum(query, window, |value, other| value >= other),
+        }
+    }
+
+    /// Calculates the LB_Keogh lower bound of the squared dynamic time warping
+    /// distance between `query` and `candidate`.
+    ///
+    /// Compares `candidate` against the envelope of `query` within `self`'s window,
+    /// which makes it cheap to discard candidates before calculating their distance.
+    /// When comparing a query against many candidates, calculate its `envelope` once instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `query` and `candidate` differ in length.
+    pub fn lb_keogh<T, L, R>(&self, query: &L, candidate: &R) -> T
+    where
+        T: Float,
+        L: AsRef<[T]> + ?Sized,
+        R: AsRef<[T]> + ?Sized,
+    {
+        self.envelope(query).lb_keogh(candidate)
+    }
+}
+
+/// The minimum and maximum components of a query within a window around each index
+///
+/// Created by `Dtw::envelope`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Envelope<T> {
+    lower: Vec<T>,
+    upper: Vec<T>,
+}
+
+impl<T> Envelope<T>
+where
+    T: Float,
+{
+    /// The minimum component within the window around each index
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    /// The maximum component within the window around each index
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+
+    /// The length of the enveloped query
+    pub fn len(&self) -> usize {
+        self.lower.len()
+    }
+
+    /// Returns `true` if the enveloped query is empty
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    /// Calculates the LB_Keogh lower bound of the squared dynamic time warping
+    /// distance between the enveloped query and `candidate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enveloped query and `candidate` differ in length.
+    pub fn lb_keogh<V>(&self, candidate: &V) -> T
+    where
+        V: AsRef<[T]> + ?Sized,
+    {
+        let candidate = candidate.as_ref();
+        assert_eq!(self.len(), candidate.len(), "LB_Keogh requires vectors of equal length");
+        let bounds = self.lower.iter().zip(self.upper.iter());
+        candidate.iter().zip(bounds).fold(T::zero(), |sum, (&value, (&lower, &upper))| {
+            let delta = if value > upper {
+                value - upper
+            } else if value < lower {
+                value - lower
+            } else {
+                T::zero()
+            };
+            sum + (delta * delta)
+        })
+    }
+}
+
+/// Calculates the extremum of `values` within `window` around each index,
+/// where `dominates(value, other)` tells whether `value` supersedes `other`.
+///
+/// Keeps the indices of candidate extrema in a monotonic deque,
+/// which takes linear time regardless of `window`.
+fn sliding_extremum<T, F>(values: &[T], window: usize, dominates: F) -> Vec<T>
+where
+    T: Copy,
+    F: Fn(T, T) -> bool,
+{
+    let mut extrema = Vec::with_capacity(values.len());
+    let mut candidates: VecDeque<usize> = VecDeque::new();
+    let mut next = 0;
+    for index in 0..values.len() {
+        let end = index.saturating_add(window).saturating_add(1).min(values.len());
+        while next < end {
+            while candidates.back().is_some_and(|&back| dominates(values[next], values[back])) {
+                candidates.pop_back();
+            }
+            candidates.push_back(next);
+            next += 1;
+        }
+        let start = index.saturating_sub(window);
+        while candidates.front().is_some_and(|&front| front < start) {
+            candidates.pop_front();
+        }
+        extrema.push(values[candidates[0]]);
+    }
+    extrema
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use dense::heap::DenseVector;
+    use dense::stack;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn distance_identical() {
+        let subject = DenseVector::from(vec![0.0, 1.0, 2.0, 1.0]);
+        expect!(Dtw::new().distance(&subject, &subject)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    fn distance_differing_lengths() {
+        let subject = DenseVector::from(vec![0.0, 1.0, 2.0, 3.0, 2.0, 0.0]);
+        let other = stack::DenseVector::from([0.0, 0.0, 1.0, 2.0, 3.0, 2.0, 1.0, 0.0]);
+        expect!(Dtw::new().squared_distance(&subject, &other)).to(be_close_to(1.0));
+        expect!(Dtw::new().with_window(1).squared_distance(&subject, &other)).to(be_close_to(1.0));
+    }
+
+    #[test]
+    fn distance_window() {
+        let subject = DenseVector::from(vec![0.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+        let other = DenseVector::from(vec![0.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+        expect!(Dtw::new().distance(&subject, &other)).to(be_equal_to(0.0));
+        expect!(Dtw::new().with_window(3).distance(&subject, &other)).to(be_equal_to(0.0));
+        expect!(Dtw::new().with_window(1).distance(&subject, &other)).to(be_close_to(2.828));
+    }
+
+    #[test]
+    fn distance_empty() {
+        let subject: DenseVector<f64> = DenseVector::from(vec![]);
+        let other = DenseVector::from(vec![1.0]);
+        expect!(Dtw::new().distance(&subject, &subject)).to(be_equal_to(0.0));
+        expect!(Dtw::new().distance(&subject, &other).is_infinite()).to(be_true());
+    }
+
+    #[test]
+    fn squared_distance_bounded() {
+        let subject = DenseVector::from(vec![0.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+        let other = DenseVector::from(vec![0.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+        let dtw = Dtw::new().with_window(1);
+        expect!(dtw.squared_distance_bounded(&subject, &other, 5.0)).to(be_none());
+        expect!(dtw.squared_distance_bounded(&subject, &other, 10.0)).to(be_some().value(8.0));
+        expect!(dtw.distance_bounded(&subject, &other, 2.0)).to(be_none());
+    }
+
+    #[test]
+    fn lb_keogh() {
+        let subject = DenseVector::from(vec![0.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+        let other = DenseVector::from(vec![0.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+        let dtw = Dtw::new().with_window(1);
+        expect!(dtw.lb_keogh(&subject, &other)).to(be_close_to(4.0));
+        expect!(dtw.lb_keogh(&subject, &other) <= dtw.squared_distance(&subject, &other)).to(be_true());
+        expect!(Dtw::new().lb_keogh(&subject, &other)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    fn distance_bounded_negative() {
+        let subject = DenseVector::from(vec![0.0, 1.0, 2.0]);
+        expect!(Dtw::new().distance_bounded(&subject, &subject, -3.0)).to(be_none());
+        expect!(Dtw::new().distance_bounded(&subject, &subject, 0.0)).to(be_some().value(0.0));
+    }
+
+    #[test]
+    fn distance_window_reuse() {
+        let subject = DenseVector::from(vec![0.0, 3.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let other = DenseVector::from(vec![0.0, 0.0, 0.0, 0.0, 0.0, 3.0, 3.0, 0.0]);
+        expect!(Dtw::new().with_window(1).squared_distance(&subject, &other)).to(be_close_to(36.0));
+        expect!(Dtw::new().with_window(2).squared_distance(&subject, &other)).to(be_close_to(36.0));
+        expect!(Dtw::new().with_window(3).squared_distance(&subject, &other)).to(be_close_to(18.0));
+        expect!(Dtw::new().squared_distance(&subject, &other)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    fn envelope() {
+        let subject = DenseVector::from(vec![1.0, 3.0, 2.0, 0.0, 4.0, 1.0]);
+        let envelope = Dtw::new().with_window(1).envelope(&subject);
+        expect!(envelope.lower()).to(be_equal_to(&[1.0, 1.0, 0.0, 0.0, 0.0, 1.0][..]));
+        expect!(envelope.upper()).to(be_equal_to(&[3.0, 3.0, 3.0, 4.0, 4.0, 4.0][..]));
+        let envelope = Dtw::new().envelope(&subject);
+        expect!(envelope.lower()).to(be_equal_to(&[0.0; 6][..]));
+        expect!(envelope.upper()).to(be_equal_to(&[4.0; 6][..]));
+    }
+
+    #[test]
+    fn envelope_lb_keogh() {
+        let subject = DenseVector::from(vec![0.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+        let other = DenseVector::from(vec![0.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+        let dtw = Dtw::new().with_window(1);
+        let envelope = dtw.envelope(&subject);
+        expect!(envelope.lb_keogh(&other)).to(be_equal_to(dtw.lb_keogh(&subject, &other)));
+        expect!(envelope.lb_keogh(&subject)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "equal length")]
+    fn lb_keogh_differing_lengths() {
+        let subject = DenseVector::from(vec![0.0, 2.0, 0.0]);
+        let other = DenseVector::from(vec![0.0, 0.0]);
+        let _ = Dtw::new().lb_keogh(&subject, &other);
+    }
+}
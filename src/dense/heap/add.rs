@@ -4,36 +4,104 @@
 
 use std::ops::{Add, AddAssign};
 
+use arrayvec::Array;
+
+use dense;
 use super::DenseVector;
 
-impl<T, V> Add<V> for DenseVector<T>
+impl<T> Add<DenseVector<T>> for DenseVector<T>
 where
-    T: AddAssign<T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    T: Copy + AddAssign<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn add(mut self, rhs: V) -> Self::Output {
+    fn add(mut self, rhs: DenseVector<T>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T> Add<&'a DenseVector<T>> for DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a DenseVector<T>) -> Self::Output {
         self.add_assign(rhs);
         self
     }
 }
 
-impl<T, V> AddAssign<V> for DenseVector<T>
+impl<T> AddAssign<DenseVector<T>> for DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: DenseVector<T>) {
+        self.add_assign(&rhs);
+    }
+}
+
+impl<'a, T> AddAssign<&'a DenseVector<T>> for DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a DenseVector<T>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs += rhs);
+    }
+}
+
+impl<T, A> Add<dense::stack::DenseVector<A>> for DenseVector<T>
 where
-    T: AddAssign<T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
 {
+    type Output = Self;
+
     #[inline]
-    fn add_assign(&mut self, rhs: V) {
-        let iter = rhs.into_iter();
-        debug_assert_eq!(self.len(), iter.len());
-        for (lhs, (_, rhs)) in self.components.iter_mut().zip(iter) {
-            *lhs += rhs;
-        }
+    fn add(mut self, rhs: dense::stack::DenseVector<A>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A> Add<&'a dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a dense::stack::DenseVector<A>) -> Self::Output {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+impl<T, A> AddAssign<dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: dense::stack::DenseVector<A>) {
+        self.add_assign(&rhs);
+    }
+}
+
+impl<'a, T, A> AddAssign<&'a dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a dense::stack::DenseVector<A>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs += rhs);
     }
 }
 
@@ -41,6 +109,10 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
+    use sparse::heap::SparseVector;
+
     use expectest::prelude::*;
 
     #[test]
@@ -70,4 +142,39 @@ mod test {
         result += &other;
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn add_assign_sparse() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0, 2.0, 3.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5), (3, -1.0)]).unwrap();
+        let expected = DenseVector::from(vec![0.0, 1.0, 1.0, 1.0, 3.0]);
+        let mut result = subject;
+        result += &other;
+        expect!(result).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_assign_sparse_out_of_bounds() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5), (3, -1.0)]).unwrap();
+        let mut result = subject;
+        result += &other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_assign_dimension_mismatch() {
+        let mut subject = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        let other = DenseVector::from(vec![1.0]);
+        subject += &other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_assign_sparse_dimension_mismatch() {
+        let mut subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5)]).unwrap().with_dim(6).unwrap();
+        subject += &other;
+    }
 }
@@ -0,0 +1,65 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use {CheckedVectorOps, DimensionMismatch};
+use super::DenseVector;
+
+impl<T> CheckedVectorOps for DenseVector<T> {
+    #[inline]
+    fn check_dim(&self, rhs: &Self) -> Result<(), DimensionMismatch> {
+        if self.len() == rhs.len() {
+            Ok(())
+        } else {
+            Err(DimensionMismatch { expected: self.len(), found: rhs.len() })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn checked_add() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = DenseVector::from(vec![2.0, 1.0, 0.0]);
+        let expected = DenseVector::from(vec![2.0, 1.5, 1.0]);
+        let result = subject.checked_add(&other);
+        expect!(result).to(be_ok().value(expected));
+    }
+
+    #[test]
+    fn checked_add_mismatch() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = DenseVector::from(vec![2.0, 1.0]);
+        let result = subject.checked_add(&other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 3, found: 2 }));
+    }
+
+    #[test]
+    fn checked_mul_add_mismatch() {
+        let subject = DenseVector::from(vec![0.0, 0.5]);
+        let other = DenseVector::from(vec![2.0, 1.0, 0.0]);
+        let result = subject.checked_mul_add(2.0, &other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 2, found: 3 }));
+    }
+
+    #[test]
+    fn checked_dot() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = DenseVector::from(vec![2.0, 1.0, 3.0]);
+        let result = subject.checked_dot(&other);
+        expect!(result).to(be_ok().value(3.5));
+    }
+
+    #[test]
+    fn checked_distance_mismatch() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = DenseVector::from(vec![2.0, 1.0]);
+        let result = subject.checked_distance(&other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 3, found: 2 }));
+    }
+}
@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::{Signed, Zero};
+use arrayvec::Array;
+
+use dense;
+use sparse::heap::SparseVector;
+use super::DenseVector;
+
+impl<T> DenseVector<T>
+where
+    T: Copy + Zero,
+{
+    /// Converts `self` into a sparse vector of dimension `self.len()`,
+    /// dropping all zero components.
+    pub fn to_sparse(&self) -> SparseVector<T> {
+        self.sparse_filtered(|value| !value.is_zero())
+    }
+
+    /// Converts `self` into a sparse vector of dimension `self.len()`,
+    /// dropping all components whose magnitude does not exceed `epsilon`.
+    pub fn to_sparse_with_threshold(&self, epsilon: T) -> SparseVector<T>
+    where
+        T: Signed + PartialOrd,
+    {
+        self.sparse_filtered(|value| value.abs() > epsilon)
+    }
+
+    fn sparse_filtered<F>(&self, mut predicate: F) -> SparseVector<T>
+    where
+        F: FnMut(T) -> bool,
+    {
+        let components = self.iter().filter(|&(_, value)| predicate(value)).collect();
+        SparseVector::from_sorted_unchecked(components)
+            .with_dim(self.len())
+            .expect("indices should lie within dimension")
+    }
+}
+
+impl<T, A> From<dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn from(vector: dense::stack::DenseVector<A>) -> Self {
+        vector.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn to_sparse() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 0.0, 0.01, 3.0]);
+        let expected = SparseVector::try_from(vec![(1, 0.5), (3, 0.01), (4, 3.0)]).unwrap().with_dim(5).unwrap();
+        expect!(subject.to_sparse()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn to_sparse_with_threshold() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 0.0, -0.01, -3.0]);
+        let expected = SparseVector::try_from(vec![(1, 0.5), (4, -3.0)]).unwrap().with_dim(5).unwrap();
+        expect!(subject.to_sparse_with_threshold(0.1)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn from_stack() {
+        let subject = dense::stack::DenseVector::from([0.0, 0.5, 1.0]);
+        let expected = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        expect!(DenseVector::from(subject)).to(be_equal_to(expected));
+    }
+}
@@ -0,0 +1,98 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::real::Real;
+
+use {Correlation, DimensionMismatch};
+use super::DenseVector;
+
+impl<T> Correlation for DenseVector<T>
+where
+    T: Real,
+{
+    type Scalar = T;
+
+    fn pearson(&self, rhs: &Self) -> Self::Scalar {
+        if self.len() != rhs.len() {
+            panic!("{}", DimensionMismatch { expected: self.len(), found: rhs.len() });
+        }
+        let pairs = self.iter().zip(rhs.iter()).map(|((_, lhs), (_, rhs))| (lhs, rhs));
+        ::pearson(self.len(), pairs)
+    }
+
+    #[cfg(feature = "std")]
+    fn spearman(&self, rhs: &Self) -> Self::Scalar {
+        if self.len() != rhs.len() {
+            panic!("{}", DimensionMismatch { expected: self.len(), found: rhs.len() });
+        }
+        ::spearman(self.iter(), rhs.iter(), self.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn pearson() {
+        let subject = DenseVector::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let other = DenseVector::from(vec![2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(subject.pearson(&other)).to(be_close_to(0.7746));
+    }
+
+    #[test]
+    fn pearson_zero_variance() {
+        let subject = DenseVector::from(vec![1.0, 1.0, 1.0]);
+        let other = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        expect!(subject.pearson(&other)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    fn spearman() {
+        let subject = DenseVector::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let other = DenseVector::from(vec![2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(subject.spearman(&other)).to(be_close_to(0.7379));
+    }
+
+    #[test]
+    fn spearman_negative() {
+        let subject = DenseVector::from(vec![-1.0, 0.0, 2.0]);
+        let other = DenseVector::from(vec![-3.0, 1.0, 0.0]);
+        expect!(subject.spearman(&other)).to(be_close_to(0.5));
+    }
+
+    #[test]
+    fn pearson_large_offset() {
+        let subject = DenseVector::from(vec![1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0, 1e9 + 4.0]);
+        let other = DenseVector::from(vec![1e9 + 2.0, 1e9 + 1.0, 1e9 + 4.0, 1e9 + 3.0]);
+        expect!(subject.pearson(&subject)).to(be_close_to(1.0));
+        expect!(subject.pearson(&other)).to(be_close_to(0.6));
+        expect!(subject.correlation_distance(&subject)).to(be_close_to(0.0));
+    }
+
+    #[test]
+    fn correlation_distance() {
+        let subject = DenseVector::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let other = DenseVector::from(vec![2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(subject.correlation_distance(&other)).to(be_close_to(0.2254));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn pearson_dimension_mismatch() {
+        let subject = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        let other = DenseVector::from(vec![1.0, 2.0]);
+        let _ = subject.pearson(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn spearman_dimension_mismatch() {
+        let subject = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        let other = DenseVector::from(vec![1.0, 2.0]);
+        let _ = subject.spearman(&other);
+    }
+}
@@ -5,6 +5,7 @@
 use num_traits::Signed;
 
 use Distance;
+use dense;
 use super::DenseVector;
 
 impl<T> Distance for DenseVector<T>
@@ -22,6 +23,13 @@ where
             sum + (delta * delta)
         })
     }
+
+    fn squared_distance_bounded(&self, rhs: &Self, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        T: PartialOrd,
+    {
+        dense::squared_distance_bounded(self.as_slice(), rhs.as_slice(), bound)
+    }
 }
 
 #[cfg(test)]
@@ -38,6 +46,14 @@ mod test {
         expect!(squared_distance).to(be_close_to(19.15));
     }
 
+    #[test]
+    fn squared_distance_bounded() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0, 2.0, 4.0]);
+        let other = DenseVector::from(vec![0.1, 0.2, 0.3, 0.4, 0.0]);
+        expect!(subject.squared_distance_bounded(&other, 20.0).unwrap()).to(be_close_to(19.15));
+        expect!(subject.squared_distance_bounded(&other, 19.0)).to(be_none());
+    }
+
     #[test]
     fn distance() {
         let subject = DenseVector::from(vec![0.0, 0.5, 1.0, 2.0, 4.0]);
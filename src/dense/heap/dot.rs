@@ -7,7 +7,7 @@ use num_traits::Num;
 use Dot;
 use super::DenseVector;
 
-impl<'a, T> Dot for DenseVector<T>
+impl<T> Dot for DenseVector<T>
 where
     T: Copy + Num,
 {
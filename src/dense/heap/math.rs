@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Float;
+
+use ElementwiseMath;
+use super::DenseVector;
+
+impl<T> ElementwiseMath for DenseVector<T>
+where
+    T: Float,
+{
+    type Scalar = T;
+
+    fn abs(mut self) -> Self {
+        self.map_in_place(T::abs);
+        self
+    }
+
+    fn sqrt(mut self) -> Self {
+        self.map_in_place(T::sqrt);
+        self
+    }
+
+    fn exp(mut self) -> Self {
+        self.map_in_place(T::exp);
+        self
+    }
+
+    fn ln(mut self) -> Self {
+        self.map_in_place(T::ln);
+        self
+    }
+
+    fn signum(mut self) -> Self {
+        self.map_in_place(T::signum);
+        self
+    }
+
+    fn clamp(mut self, min: Self::Scalar, max: Self::Scalar) -> Self {
+        assert!(min <= max, "clamp requires min <= max");
+        self.map_in_place(|value| value.max(min).min(max));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn abs() {
+        let subject = DenseVector::from(vec![1.0, -2.0, 0.0]);
+        let expected = DenseVector::from(vec![1.0, 2.0, 0.0]);
+        expect!(subject.abs()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn sqrt() {
+        let subject = DenseVector::from(vec![1.0, 4.0, 9.0]);
+        let expected = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        expect!(subject.sqrt()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn exp_ln() {
+        let subject = DenseVector::from(vec![1.0, 4.0, 9.0]);
+        let result = subject.clone().ln().exp();
+        for ((_, lhs), (_, rhs)) in result.iter().zip(subject.iter()) {
+            expect!(lhs).to(be_close_to(rhs));
+        }
+    }
+
+    #[test]
+    fn signum() {
+        let subject = DenseVector::from(vec![3.0, -2.0]);
+        let expected = DenseVector::from(vec![1.0, -1.0]);
+        expect!(subject.signum()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn clamp() {
+        let subject = DenseVector::from(vec![-3.0, 0.5, 2.0]);
+        let expected = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        expect!(subject.clamp(0.0, 1.0)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "min <= max")]
+    fn clamp_invalid() {
+        let subject = DenseVector::from(vec![-3.0, 0.5, 2.0]);
+        let _ = subject.clamp(1.0, 0.0);
+    }
+}
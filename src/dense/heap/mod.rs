@@ -8,16 +8,30 @@ use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
 
 use num_traits::{NumAssign, MulAdd, MulAddAssign};
 
-use {Vector, VectorOps, VectorAssignOps};
+use {Error, Vector, VectorOps, VectorAssignOps};
 
 mod add;
 mod sub;
 mod mul;
 mod div;
 mod mul_add;
+mod hadamard;
+mod neg;
+mod map;
+mod math;
 
 mod dot;
 mod distance;
+mod norm;
+mod cosine;
+mod correlation;
+mod stats;
+mod select;
+mod checked;
+
+mod convert;
+#[cfg(feature = "serde")]
+mod serde;
 
 mod debug;
 mod iter;
@@ -31,6 +45,17 @@ pub struct DenseVector<T> {
 }
 
 impl<T> DenseVector<T> {
+    /// Creates a `DenseVector` from the components yielded by `iter`.
+    ///
+    /// Never fails, as heap-allocated vectors have no capacity limit,
+    /// but mirrors the stack-allocated vector's constructor.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Ok(Self { components: iter.into_iter().collect() })
+    }
+
     /// The number of components in `self`
     #[inline]
     pub fn len(&self) -> usize {
@@ -48,6 +73,25 @@ impl<T> DenseVector<T> {
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter::new(&self.components[..])
     }
+
+    /// A slice of `self`'s components
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.components[..]
+    }
+
+    /// A mutable slice of `self`'s components
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.components[..]
+    }
+}
+
+impl<T> AsRef<[T]> for DenseVector<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.components[..]
+    }
 }
 
 impl<T> From<Vec<T>> for DenseVector<T> {
@@ -89,4 +133,11 @@ mod test {
         let subject = DenseVector::from(values.clone());
         expect!(subject.components).to(be_equal_to(values));
     }
+
+    #[test]
+    fn try_from_iter() {
+        let values = vec![0.0, 1.0, 0.5];
+        let subject = DenseVector::try_from_iter(values.clone()).unwrap();
+        expect!(subject.components).to(be_equal_to(values));
+    }
 }
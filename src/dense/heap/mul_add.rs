@@ -2,15 +2,16 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use num_traits::{MulAdd, MulAddAssign};
+use num_traits::{MulAdd, MulAddAssign, Zero};
+use ordered_iter::OrderedMapIterator;
 
 use super::DenseVector;
 
 impl<T, V> MulAdd<T, V> for DenseVector<T>
 where
-    T: Copy + MulAddAssign<T, T>,
+    T: Copy + Zero + MulAddAssign<T, T>,
     V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    <V as IntoIterator>::IntoIter: OrderedMapIterator<Key = usize, Val = T>,
 {
     type Output = Self;
 
@@ -23,16 +24,42 @@ where
 
 impl<T, V> MulAddAssign<T, V> for DenseVector<T>
 where
-    T: Copy + MulAddAssign<T, T>,
+    T: Copy + Zero + MulAddAssign<T, T>,
     V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    <V as IntoIterator>::IntoIter: OrderedMapIterator<Key = usize, Val = T>,
 {
     #[inline]
     fn mul_add_assign(&mut self, a: T, b: V) {
-        let iter = b.into_iter();
-        debug_assert_eq!(self.len(), iter.len());
-        for (lhs, (_, rhs)) in self.components.iter_mut().zip(iter) {
-            lhs.mul_add_assign(a, rhs);
+        let mut iter = b.into_iter().peekable();
+        for (index, lhs) in self.components.iter_mut().enumerate() {
+            let rhs = match iter.peek() {
+                Some(&(rhs_index, _)) if rhs_index == index => iter.next().map(|(_, rhs)| rhs),
+                _ => None,
+            };
+            lhs.mul_add_assign(a, rhs.unwrap_or_else(T::zero));
+        }
+        if let Some((index, _)) = iter.next() {
+            panic!("index {} is out of bounds for dimension {}", index, self.len());
+        }
+    }
+}
+
+impl<T> DenseVector<T>
+where
+    T: Copy + MulAdd<T, T, Output = T>,
+{
+    /// Calculates `self += a * x`, updating only the components yielded by `x`.
+    ///
+    /// Unlike `mul_add_assign`, which scales `self` by `a`, this scales `x`,
+    /// making it cost O(nnz) for a sparse `x`.
+    #[inline]
+    pub fn axpy<V>(&mut self, a: T, x: V)
+    where
+        V: IntoIterator<Item = (usize, T)>,
+    {
+        for (index, rhs) in x {
+            let lhs = &mut self.components[index];
+            *lhs = rhs.mul_add(a, *lhs);
         }
     }
 }
@@ -41,6 +68,10 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
+    use sparse::heap::SparseVector;
+
     use expectest::prelude::*;
 
     #[test]
@@ -70,4 +101,33 @@ mod test {
         result.mul_add_assign(2.0, &subject);
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn mul_add_assign_sparse() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0, 2.0, 3.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5), (3, -1.0)]).unwrap();
+        let expected = DenseVector::from(vec![0.0, 1.5, 2.0, 3.0, 6.0]);
+        let mut result = subject;
+        result.mul_add_assign(2.0, &other);
+        expect!(result).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn mul_add_assign_sparse_out_of_bounds() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5), (3, -1.0)]).unwrap();
+        let mut result = subject;
+        result.mul_add_assign(2.0, &other);
+    }
+
+    #[test]
+    fn axpy_sparse() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0, 2.0, 3.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5), (3, -1.0)]).unwrap();
+        let expected = DenseVector::from(vec![0.0, 1.5, 1.0, 0.0, 3.0]);
+        let mut result = subject;
+        result.axpy(2.0, &other);
+        expect!(result).to(be_equal_to(expected));
+    }
 }
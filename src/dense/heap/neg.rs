@@ -0,0 +1,34 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::Neg;
+
+use super::DenseVector;
+
+impl<T> Neg for DenseVector<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.map_in_place(T::neg);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn neg() {
+        let subject = DenseVector::from(vec![1.0, -2.0, 0.0]);
+        let expected = DenseVector::from(vec![-1.0, 2.0, 0.0]);
+        expect!(-subject).to(be_equal_to(expected));
+    }
+}
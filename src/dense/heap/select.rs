@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Signed;
+
+use {Selection, select, sparsify, ascending, descending};
+use sparse;
+use super::DenseVector;
+
+impl<T> Selection for DenseVector<T>
+where
+    T: Copy + PartialOrd,
+{
+    type Scalar = T;
+
+    fn top_k(&self, k: usize) -> Vec<(usize, T)> {
+        select(self.iter(), k, descending)
+    }
+
+    fn bottom_k(&self, k: usize) -> Vec<(usize, T)> {
+        select(self.iter(), k, ascending)
+    }
+
+    fn top_k_by_magnitude(&self, k: usize) -> Vec<(usize, T)>
+    where
+        T: Signed,
+    {
+        select(self.iter(), k, |lhs, rhs| descending(&lhs.abs(), &rhs.abs()))
+    }
+
+    fn argsort(&self) -> Vec<(usize, T)> {
+        select(self.iter(), usize::MAX, ascending)
+    }
+
+    fn sparsify_top_k(&self, k: usize) -> sparse::heap::SparseVector<T>
+    where
+        T: Signed,
+    {
+        sparsify(self.iter(), k, Some(self.len()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::f64;
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn top_k() {
+        let subject = DenseVector::from(vec![1.0, -5.0, 3.0, 4.0, 3.0]);
+        expect!(subject.top_k(3)).to(be_equal_to(vec![(3, 4.0), (2, 3.0), (4, 3.0)]));
+        expect!(subject.top_k(0)).to(be_equal_to(vec![]));
+        expect!(subject.top_k(10).len()).to(be_equal_to(5));
+    }
+
+    #[test]
+    fn bottom_k() {
+        let subject = DenseVector::from(vec![1.0, -5.0, 3.0, 4.0, 3.0]);
+        expect!(subject.bottom_k(2)).to(be_equal_to(vec![(1, -5.0), (0, 1.0)]));
+    }
+
+    #[test]
+    fn top_k_by_magnitude() {
+        let subject = DenseVector::from(vec![1.0, -5.0, 3.0, 4.0, 3.0]);
+        expect!(subject.top_k_by_magnitude(2)).to(be_equal_to(vec![(1, -5.0), (3, 4.0)]));
+    }
+
+    #[test]
+    fn argsort() {
+        let subject = DenseVector::from(vec![1.0, -5.0, 3.0, 4.0, 3.0]);
+        let expected = vec![(1, -5.0), (0, 1.0), (2, 3.0), (4, 3.0), (3, 4.0)];
+        expect!(subject.argsort()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn nan_last() {
+        let subject = DenseVector::from(vec![1.0, f64::NAN, 3.0]);
+        let top = subject.top_k(2);
+        expect!(top).to(be_equal_to(vec![(2, 3.0), (0, 1.0)]));
+        let bottom = subject.bottom_k(2);
+        expect!(bottom).to(be_equal_to(vec![(0, 1.0), (2, 3.0)]));
+    }
+
+    #[test]
+    fn sparsify_top_k() {
+        let subject = DenseVector::from(vec![1.0, -5.0, 0.0, 4.0, 3.0]);
+        let expected = sparse::heap::SparseVector::try_from(vec![(1, -5.0), (3, 4.0)]).unwrap().with_dim(5).unwrap();
+        expect!(subject.sparsify_top_k(2)).to(be_equal_to(expected));
+    }
+}
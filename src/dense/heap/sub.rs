@@ -4,36 +4,104 @@
 
 use std::ops::{Sub, SubAssign};
 
+use arrayvec::Array;
+
+use dense;
 use super::DenseVector;
 
-impl<T, V> Sub<V> for DenseVector<T>
+impl<T> Sub<DenseVector<T>> for DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: DenseVector<T>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T> Sub<&'a DenseVector<T>> for DenseVector<T>
 where
-    T: SubAssign<T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    T: Copy + SubAssign<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn sub(mut self, rhs: V) -> Self::Output {
+    fn sub(mut self, rhs: &'a DenseVector<T>) -> Self::Output {
         self.sub_assign(rhs);
         self
     }
 }
 
-impl<T, V> SubAssign<V> for DenseVector<T>
+impl<T> SubAssign<DenseVector<T>> for DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: DenseVector<T>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+impl<'a, T> SubAssign<&'a DenseVector<T>> for DenseVector<T>
 where
-    T: SubAssign<T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    T: Copy + SubAssign<T>,
 {
     #[inline]
-    fn sub_assign(&mut self, rhs: V) {
-        let iter = rhs.into_iter();
-        debug_assert_eq!(self.len(), iter.len());
-        for (lhs, (_, rhs)) in self.components.iter_mut().zip(iter) {
-            *lhs -= rhs;
-        }
+    fn sub_assign(&mut self, rhs: &'a DenseVector<T>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs -= rhs);
+    }
+}
+
+impl<T, A> Sub<dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: dense::stack::DenseVector<A>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A> Sub<&'a dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &'a dense::stack::DenseVector<A>) -> Self::Output {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+impl<T, A> SubAssign<dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: dense::stack::DenseVector<A>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+impl<'a, T, A> SubAssign<&'a dense::stack::DenseVector<A>> for DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: &'a dense::stack::DenseVector<A>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs -= rhs);
     }
 }
 
@@ -41,6 +109,10 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
+    use sparse::heap::SparseVector;
+
     use expectest::prelude::*;
 
     #[test]
@@ -70,4 +142,30 @@ mod test {
         result -= &other;
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn sub_assign_sparse() {
+        let subject = DenseVector::from(vec![0.0, 0.5, 1.0, 2.0, 3.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5), (3, -1.0)]).unwrap();
+        let expected = DenseVector::from(vec![0.0, 0.0, 1.0, 3.0, 3.0]);
+        let mut result = subject;
+        result -= &other;
+        expect!(result).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn sub_assign_dimension_mismatch() {
+        let mut subject = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        let other = DenseVector::from(vec![1.0]);
+        subject -= &other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn sub_assign_sparse_dimension_mismatch() {
+        let mut subject = DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from(vec![(1, 0.5)]).unwrap().with_dim(6).unwrap();
+        subject -= &other;
+    }
 }
@@ -77,7 +77,7 @@ where
 impl<'a, T> Iter<'a, T> {
     /// Creates an `Iter` from a slice of dense components
     pub fn new(iter: &'a [T]) -> Self {
-        Iter { index: 0, inner: iter.into_iter() }
+        Iter { index: 0, inner: iter.iter() }
     }
 }
 
@@ -137,7 +137,7 @@ mod test {
 
     #[test]
     fn iter() {
-        let values = vec![0.1, 0.2, 0.3, 0.4, 0.5];
+        let values = [0.1, 0.2, 0.3, 0.4, 0.5];
         let iter = Iter::new(&values[..]);
         let subject: Vec<_> = iter.collect();
         let expected = vec![(0, 0.1), (1, 0.2), (2, 0.3), (3, 0.4), (4, 0.5)];
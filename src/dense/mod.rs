@@ -4,8 +4,85 @@
 
 //! Dense vector representations.
 
+use num_traits::Signed;
+
+use DimensionMismatch;
+
 mod iter;
 
 pub mod stack;
 #[cfg(feature = "std")]
 pub mod heap;
+
+#[cfg(feature = "std")]
+pub mod dtw;
+
+/// The number of squared differences accumulated independently,
+/// allowing the compiler to vectorize their accumulation.
+const LANES: usize = 8;
+
+/// The number of components between checks against the bound.
+const BLOCK: usize = 8 * LANES;
+
+/// Applies `f` to each component of `lhs` and the corresponding component of `rhs`.
+///
+/// Panics if `lhs` and `rhs` differ in length.
+#[inline]
+fn zip_assign<T, F>(lhs: &mut [T], rhs: &[T], f: F)
+where
+    T: Copy,
+    F: Fn(&mut T, T),
+{
+    if lhs.len() != rhs.len() {
+        panic!("{}", DimensionMismatch { expected: lhs.len(), found: rhs.len() });
+    }
+    for (lhs, &rhs) in lhs.iter_mut().zip(rhs.iter()) {
+        f(lhs, rhs);
+    }
+}
+
+/// Calculates the squared euclidian distance between `lhs` and `rhs`,
+/// if it does not exceed `bound`, checking the partial distance once per block.
+fn squared_distance_bounded<T>(lhs: &[T], rhs: &[T], bound: T) -> Option<T>
+where
+    T: Copy + Signed + PartialOrd,
+{
+    debug_assert_eq!(lhs.len(), rhs.len());
+    let mut lanes = [T::zero(); LANES];
+    for (lhs, rhs) in lhs.chunks(BLOCK).zip(rhs.chunks(BLOCK)) {
+        let lhs_chunks = lhs.chunks_exact(LANES);
+        let rhs_chunks = rhs.chunks_exact(LANES);
+        let (lhs_rest, rhs_rest) = (lhs_chunks.remainder(), rhs_chunks.remainder());
+        for (lhs, rhs) in lhs_chunks.zip(rhs_chunks) {
+            for ((lane, &lhs), &rhs) in lanes.iter_mut().zip(lhs).zip(rhs) {
+                let delta = lhs - rhs;
+                *lane = *lane + (delta * delta);
+            }
+        }
+        for ((lane, &lhs), &rhs) in lanes.iter_mut().zip(lhs_rest).zip(rhs_rest) {
+            let delta = lhs - rhs;
+            *lane = *lane + (delta * delta);
+        }
+        let sum = lanes.iter().fold(T::zero(), |sum, &lane| sum + lane);
+        if sum > bound {
+            return None;
+        }
+    }
+    ::bounded(lanes.iter().fold(T::zero(), |sum, &lane| sum + lane), bound)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn squared_distance_bounded_blocks() {
+        let lhs: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let rhs: Vec<f64> = (0..100).map(|i| (i + 1) as f64).collect();
+        expect!(squared_distance_bounded(&lhs, &rhs, 100.0)).to(be_some().value(100.0));
+        expect!(squared_distance_bounded(&lhs, &rhs, 99.0)).to(be_none());
+        expect!(squared_distance_bounded(&lhs[..5], &rhs[..5], 99.0)).to(be_some().value(5.0));
+    }
+}
@@ -6,38 +6,114 @@ use std::ops::{Add, AddAssign};
 
 use arrayvec::Array;
 
+use dense;
 use super::DenseVector;
 
-impl<T, A, V> Add<V> for DenseVector<A>
+impl<T, A, B> Add<DenseVector<B>> for DenseVector<A>
 where
-    T: AddAssign<T>,
+    T: Copy + AddAssign<T>,
     A: Array<Item = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    B: Array<Item = T>,
 {
     type Output = Self;
 
     #[inline]
-    fn add(mut self, rhs: V) -> Self::Output {
+    fn add(mut self, rhs: DenseVector<B>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A, B> Add<&'a DenseVector<B>> for DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a DenseVector<B>) -> Self::Output {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+impl<T, A, B> AddAssign<DenseVector<B>> for DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: DenseVector<B>) {
+        self.add_assign(&rhs);
+    }
+}
+
+impl<'a, T, A, B> AddAssign<&'a DenseVector<B>> for DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a DenseVector<B>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs += rhs);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Add<dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: dense::heap::DenseVector<T>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, A> Add<&'a dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a dense::heap::DenseVector<T>) -> Self::Output {
         self.add_assign(rhs);
         self
     }
 }
 
-impl<T, A, V> AddAssign<V> for DenseVector<A>
+#[cfg(feature = "std")]
+impl<T, A> AddAssign<dense::heap::DenseVector<T>> for DenseVector<A>
 where
-    T: AddAssign<T>,
+    T: Copy + AddAssign<T>,
     A: Array<Item = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
 {
     #[inline]
-    fn add_assign(&mut self, rhs: V) {
-        let iter = rhs.into_iter();
-        debug_assert_eq!(self.len(), iter.len());
-        for (lhs, (_, rhs)) in self.components.iter_mut().zip(iter) {
-            *lhs += rhs;
-        }
+    fn add_assign(&mut self, rhs: dense::heap::DenseVector<T>) {
+        self.add_assign(&rhs);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, A> AddAssign<&'a dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a dense::heap::DenseVector<T>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs += rhs);
     }
 }
 
@@ -45,6 +121,10 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
+    use sparse::stack::SparseVector;
+
     use expectest::prelude::*;
 
     #[test]
@@ -74,4 +154,39 @@ mod test {
         result += &other;
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn add_assign_sparse() {
+        let subject = DenseVector::from([0.0, 0.5, 1.0, 2.0, 3.0]);
+        let other = SparseVector::try_from([(1, 0.5), (3, -1.0)]).unwrap();
+        let expected = DenseVector::from([0.0, 1.0, 1.0, 1.0, 3.0]);
+        let mut result = subject;
+        result += &other;
+        expect!(result).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_assign_sparse_out_of_bounds() {
+        let subject = DenseVector::from([0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from([(1, 0.5), (3, -1.0)]).unwrap();
+        let mut result = subject;
+        result += &other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_assign_dimension_mismatch() {
+        let mut subject = DenseVector::from([1.0, 2.0, 3.0]);
+        let other = DenseVector::from([1.0]);
+        subject += &other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_assign_sparse_dimension_mismatch() {
+        let mut subject = DenseVector::from([0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from([(1, 0.5)]).unwrap().with_dim(6).unwrap();
+        subject += &other;
+    }
 }
@@ -0,0 +1,56 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use arrayvec::Array;
+
+use {CheckedVectorOps, DimensionMismatch};
+use super::DenseVector;
+
+impl<T, A> CheckedVectorOps for DenseVector<A>
+where
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn check_dim(&self, rhs: &Self) -> Result<(), DimensionMismatch> {
+        if self.len() == rhs.len() {
+            Ok(())
+        } else {
+            Err(DimensionMismatch { expected: self.len(), found: rhs.len() })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    type Type = DenseVector<[f32; 4]>;
+
+    #[test]
+    fn checked_sub() {
+        let subject = Type::try_from_iter(vec![2.0, 1.5, 1.0]).unwrap();
+        let other = Type::try_from_iter(vec![2.0, 1.0, 0.0]).unwrap();
+        let expected = Type::try_from_iter(vec![0.0, 0.5, 1.0]).unwrap();
+        let result = subject.checked_sub(&other);
+        expect!(result).to(be_ok().value(expected));
+    }
+
+    #[test]
+    fn checked_sub_mismatch() {
+        let subject = Type::try_from_iter(vec![2.0, 1.5, 1.0]).unwrap();
+        let other = Type::try_from_iter(vec![2.0, 1.0, 0.0, 1.0]).unwrap();
+        let result = subject.checked_sub(&other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 3, found: 4 }));
+    }
+
+    #[test]
+    fn checked_squared_distance_mismatch() {
+        let subject = Type::try_from_iter(vec![2.0, 1.5, 1.0]).unwrap();
+        let other = Type::try_from_iter(vec![2.0, 1.0]).unwrap();
+        let result = subject.checked_squared_distance(&other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 3, found: 2 }));
+    }
+}
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::convert::TryFrom;
+
+use num_traits::{Signed, Zero};
+use arrayvec::Array;
+
+use {dense, Error};
+use sparse::heap::SparseVector;
+use super::DenseVector;
+
+impl<T, A> DenseVector<A>
+where
+    T: Copy + Zero,
+    A: Array<Item = T>,
+{
+    /// Converts `self` into a sparse vector of dimension `self.len()`,
+    /// dropping all zero components.
+    pub fn to_sparse(&self) -> SparseVector<T> {
+        self.sparse_filtered(|value| !value.is_zero())
+    }
+
+    /// Converts `self` into a sparse vector of dimension `self.len()`,
+    /// dropping all components whose magnitude does not exceed `epsilon`.
+    pub fn to_sparse_with_threshold(&self, epsilon: T) -> SparseVector<T>
+    where
+        T: Signed + PartialOrd,
+    {
+        self.sparse_filtered(|value| value.abs() > epsilon)
+    }
+
+    fn sparse_filtered<F>(&self, mut predicate: F) -> SparseVector<T>
+    where
+        F: FnMut(T) -> bool,
+    {
+        let components = self.iter().filter(|&(_, value)| predicate(value)).collect();
+        SparseVector::from_sorted_unchecked(components)
+            .with_dim(self.len())
+            .expect("indices should lie within dimension")
+    }
+}
+
+impl<T, A> TryFrom<dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    A: Array<Item = T>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vector: dense::heap::DenseVector<T>) -> Result<Self, Self::Error> {
+        Self::try_from_iter(vector.into_iter().map(|(_, value)| value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn to_sparse() {
+        let subject = DenseVector::from([0.0, 0.5, 0.0, 0.01, 3.0]);
+        let expected = SparseVector::try_from(vec![(1, 0.5), (3, 0.01), (4, 3.0)]).unwrap().with_dim(5).unwrap();
+        expect!(subject.to_sparse()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn to_sparse_with_threshold() {
+        let subject = DenseVector::from([0.0, 0.5, 0.0, -0.01, -3.0]);
+        let expected = SparseVector::try_from(vec![(1, 0.5), (4, -3.0)]).unwrap().with_dim(5).unwrap();
+        expect!(subject.to_sparse_with_threshold(0.1)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn try_from_heap() {
+        let subject = dense::heap::DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let result: DenseVector<[f32; 4]> = DenseVector::try_from(subject).unwrap();
+        expect!(result.as_slice()).to(be_equal_to(&[0.0, 0.5, 1.0][..]));
+    }
+
+    #[test]
+    fn try_from_heap_exceeding_capacity() {
+        let subject = dense::heap::DenseVector::from(vec![0.0, 0.5, 1.0]);
+        let result: Result<DenseVector<[f32; 2]>, _> = DenseVector::try_from(subject);
+        expect!(result).to(be_err().value(Error::CapacityExceeded { capacity: 2 }));
+    }
+}
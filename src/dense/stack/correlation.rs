@@ -0,0 +1,94 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::real::Real;
+
+use arrayvec::Array;
+
+use {Correlation, DimensionMismatch};
+use super::DenseVector;
+
+impl<T, A> Correlation for DenseVector<A>
+where
+    T: Real,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn pearson(&self, rhs: &Self) -> Self::Scalar {
+        if self.len() != rhs.len() {
+            panic!("{}", DimensionMismatch { expected: self.len(), found: rhs.len() });
+        }
+        let pairs = self.iter().zip(rhs.iter()).map(|((_, lhs), (_, rhs))| (lhs, rhs));
+        ::pearson(self.len(), pairs)
+    }
+
+    #[cfg(feature = "std")]
+    fn spearman(&self, rhs: &Self) -> Self::Scalar {
+        if self.len() != rhs.len() {
+            panic!("{}", DimensionMismatch { expected: self.len(), found: rhs.len() });
+        }
+        ::spearman(self.iter(), rhs.iter(), self.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn pearson() {
+        let subject = DenseVector::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let other = DenseVector::from([2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(subject.pearson(&other)).to(be_close_to(0.7746));
+    }
+
+    #[test]
+    fn pearson_zero_variance() {
+        let subject = DenseVector::from([1.0, 1.0, 1.0]);
+        let other = DenseVector::from([1.0, 2.0, 3.0]);
+        expect!(subject.pearson(&other)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    fn spearman() {
+        let subject = DenseVector::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let other = DenseVector::from([2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(subject.spearman(&other)).to(be_close_to(0.7379));
+    }
+
+    #[test]
+    fn spearman_negative() {
+        let subject = DenseVector::from([-1.0, 0.0, 2.0]);
+        let other = DenseVector::from([-3.0, 1.0, 0.0]);
+        expect!(subject.spearman(&other)).to(be_close_to(0.5));
+    }
+
+    #[test]
+    fn correlation_distance() {
+        let subject = DenseVector::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let other = DenseVector::from([2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(subject.correlation_distance(&other)).to(be_close_to(0.2254));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn pearson_dimension_mismatch() {
+        type Type = DenseVector<[f32; 4]>;
+        let subject = Type::try_from_iter(vec![1.0, 2.0, 3.0]).unwrap();
+        let other = Type::try_from_iter(vec![1.0, 2.0]).unwrap();
+        let _ = subject.pearson(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn spearman_dimension_mismatch() {
+        type Type = DenseVector<[f32; 4]>;
+        let subject = Type::try_from_iter(vec![1.0, 2.0, 3.0]).unwrap();
+        let other = Type::try_from_iter(vec![1.0, 2.0]).unwrap();
+        let _ = subject.spearman(&other);
+    }
+}
@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::real::Real;
+
+use arrayvec::Array;
+
+use Cosine;
+use super::DenseVector;
+
+impl<T, A> Cosine for DenseVector<A>
+where
+    T: Real,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn cosine_similarity(&self, rhs: &Self) -> Self::Scalar {
+        debug_assert_eq!(self.len(), rhs.len());
+        let lhs_iter = self.components.iter();
+        let rhs_iter = rhs.components.iter();
+        let zero = T::zero();
+        let (dot, lhs_sum, rhs_sum) = lhs_iter.zip(rhs_iter).fold((zero, zero, zero), |sums, (&lhs, &rhs)| {
+            let (dot, lhs_sum, rhs_sum) = sums;
+            (dot + (lhs * rhs), lhs_sum + (lhs * lhs), rhs_sum + (rhs * rhs))
+        });
+        ::cosine_similarity(dot, lhs_sum, rhs_sum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn cosine_similarity() {
+        let subject = DenseVector::from([1.0, 2.0, 0.0, -1.0]);
+        let other = DenseVector::from([2.0, 1.0, 1.0, 0.0]);
+        expect!(subject.cosine_similarity(&other)).to(be_close_to(0.6667));
+    }
+
+    #[test]
+    fn cosine_distance() {
+        let subject = DenseVector::from([1.0, 2.0, 0.0, -1.0]);
+        let other = DenseVector::from([2.0, 1.0, 1.0, 0.0]);
+        expect!(subject.cosine_distance(&other)).to(be_close_to(0.3333));
+    }
+
+    #[test]
+    fn cosine_zero() {
+        let subject = DenseVector::from([0.0, 0.0, 0.0, 0.0]);
+        let other = DenseVector::from([2.0, 1.0, 1.0, 0.0]);
+        expect!(subject.cosine_similarity(&other)).to(be_equal_to(0.0));
+        expect!(subject.cosine_distance(&other)).to(be_equal_to(1.0));
+    }
+}
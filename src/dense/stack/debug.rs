@@ -16,9 +16,10 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let _ = write!(f, "[");
         for (index, item) in self.components.iter().enumerate() {
-            try! {
-                if index > 0 { write!(f, ", {:?}", item) }
-                else { write!(f, "{:?}", item) }
+            if index > 0 {
+                write!(f, ", {:?}", item)?;
+            } else {
+                write!(f, "{:?}", item)?;
             }
         }
         let _ = write!(f, "]");
@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::{Mul, Div};
+
+use arrayvec::Array;
+
+use Hadamard;
+use super::DenseVector;
+
+impl<T, A> Hadamard for DenseVector<A>
+where
+    T: Copy + Mul<T, Output = T> + Div<T, Output = T>,
+    A: Array<Item = T>,
+{
+    fn hadamard_mul_assign(&mut self, rhs: &Self) {
+        self.zip_with_in_place(rhs, |lhs, rhs| lhs * rhs);
+    }
+
+    fn hadamard_div_assign(&mut self, rhs: &Self) {
+        self.zip_with_in_place(rhs, |lhs, rhs| lhs / rhs);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn hadamard_mul() {
+        let subject = DenseVector::from([1.0, 2.0, 3.0, 4.0]);
+        let other = DenseVector::from([0.5, 0.0, 2.0, 1.0]);
+        let expected = DenseVector::from([0.5, 0.0, 6.0, 4.0]);
+        expect!(subject.hadamard_mul(&other)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn hadamard_div_assign() {
+        let mut subject = DenseVector::from([1.0, 2.0, 3.0, 4.0]);
+        let other = DenseVector::from([0.5, 4.0, 2.0, 1.0]);
+        let expected = DenseVector::from([2.0, 0.5, 1.5, 4.0]);
+        subject.hadamard_div_assign(&other);
+        expect!(subject).to(be_equal_to(expected));
+    }
+}
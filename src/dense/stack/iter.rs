@@ -67,7 +67,7 @@ mod test {
     #[test]
     fn into_iter() {
         let values = [0.1, 0.2, 0.3, 0.4, 0.5];
-        let subject = DenseVector::from(values.clone());
+        let subject = DenseVector::from(values);
         let expected = vec![(0, 0.1), (1, 0.2), (2, 0.3), (3, 0.4), (4, 0.5)];
         let output: Vec<_> = subject.into_iter().collect();
         expect!(output).to(be_equal_to(expected));
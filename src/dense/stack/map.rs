@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use arrayvec::Array;
+
+use DimensionMismatch;
+use super::DenseVector;
+
+impl<T, A> DenseVector<A>
+where
+    T: Copy,
+    A: Array<Item = T>,
+{
+    /// Applies `f` to each of `self`'s components.
+    pub fn map<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        self.map_in_place(f);
+        self
+    }
+
+    /// Replaces each of `self`'s components with the result of applying `f` to it.
+    pub fn map_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        for value in self.components.iter_mut() {
+            *value = f(*value);
+        }
+    }
+
+    /// Applies `f` to each pair of corresponding components of `self` and `rhs`.
+    ///
+    /// Panics if `self` and `rhs` differ in length.
+    pub fn zip_with<F>(mut self, rhs: &Self, f: F) -> Self
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.zip_with_in_place(rhs, f);
+        self
+    }
+
+    /// Replaces each of `self`'s components with the result of applying `f`
+    /// to it and the corresponding component of `rhs`.
+    ///
+    /// Panics if `self` and `rhs` differ in length.
+    pub fn zip_with_in_place<F>(&mut self, rhs: &Self, mut f: F)
+    where
+        F: FnMut(T, T) -> T,
+    {
+        if self.len() != rhs.len() {
+            panic!("{}", DimensionMismatch { expected: self.len(), found: rhs.len() });
+        }
+        for (lhs, &rhs) in self.components.iter_mut().zip(rhs.components.iter()) {
+            *lhs = f(*lhs, rhs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn map() {
+        let subject = DenseVector::from([1.0, 2.0, 3.0]);
+        let expected = DenseVector::from([2.0, 4.0, 6.0]);
+        expect!(subject.map(|value| value * 2.0)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn map_in_place() {
+        let mut subject = DenseVector::from([1.0, -2.0, 3.0]);
+        let expected = DenseVector::from([1.0, 0.0, 3.0]);
+        subject.map_in_place(|value| if value < 0.0 { 0.0 } else { value });
+        expect!(subject).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn zip_with() {
+        let subject = DenseVector::from([1.0, 5.0, 3.0]);
+        let other = DenseVector::from([2.0, 4.0, 3.0]);
+        let expected = DenseVector::from([2.0, 5.0, 3.0]);
+        expect!(subject.zip_with(&other, f64::max)).to(be_equal_to(expected));
+    }
+}
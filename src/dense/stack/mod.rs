@@ -9,16 +9,32 @@ use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
 use num_traits::{NumAssign, MulAdd, MulAddAssign};
 use arrayvec::{Array, ArrayVec};
 
-use {Vector, VectorOps, VectorAssignOps};
+use {Error, Vector, VectorOps, VectorAssignOps};
 
 mod add;
 mod sub;
 mod mul;
 mod div;
 mod mul_add;
+mod hadamard;
+mod neg;
+mod map;
+mod math;
 
 mod dot;
 mod distance;
+mod norm;
+mod cosine;
+mod correlation;
+mod stats;
+#[cfg(feature = "std")]
+mod select;
+mod checked;
+
+#[cfg(feature = "std")]
+mod convert;
+#[cfg(feature = "serde")]
+mod serde;
 
 mod debug;
 mod iter;
@@ -37,6 +53,22 @@ impl<T, A> DenseVector<A>
 where
     A: Array<Item = T>,
 {
+    /// Creates a `DenseVector` from the components yielded by `iter`.
+    ///
+    /// Fails if the number of components exceeds the vector's capacity.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut components = ArrayVec::new();
+        for component in iter {
+            if components.try_push(component).is_err() {
+                return Err(Error::CapacityExceeded { capacity: A::capacity() });
+            }
+        }
+        Ok(Self { components })
+    }
+
     /// The number of components in `self`
     #[inline]
     pub fn len(&self) -> usize {
@@ -54,6 +86,18 @@ where
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter::new(&self.components[..])
     }
+
+    /// A slice of `self`'s components
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.components[..]
+    }
+
+    /// A mutable slice of `self`'s components
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.components[..]
+    }
 }
 
 impl<T, A> Clone for DenseVector<A>
@@ -77,6 +121,16 @@ where
     }
 }
 
+impl<T, A> AsRef<[T]> for DenseVector<A>
+where
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.components[..]
+    }
+}
+
 impl<T, A> From<A> for DenseVector<A>
 where
     A: Array<Item = T>,
@@ -129,8 +183,22 @@ mod test {
     #[test]
     fn from() {
         const VALUES: [f32; 5] = [0.0, 1.0, 0.5, 0.25, 0.125];
-        let subject = DenseVector::from(VALUES.clone());
+        let subject = DenseVector::from(VALUES);
         let expected = ArrayVec::from(VALUES);
         expect!(subject.components).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn try_from_iter() {
+        let values = vec![0.0, 1.0, 0.5];
+        let subject: DenseVector<[f32; 4]> = DenseVector::try_from_iter(values.clone()).unwrap();
+        expect!(subject.components.to_vec()).to(be_equal_to(values));
+    }
+
+    #[test]
+    fn try_from_iter_exceeding_capacity() {
+        let values = vec![0.0, 1.0, 0.5];
+        let subject: Result<DenseVector<[f32; 2]>, _> = DenseVector::try_from_iter(values);
+        expect!(subject).to(be_err().value(Error::CapacityExceeded { capacity: 2 }));
+    }
 }
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::{Signed, real::Real};
+use arrayvec::Array;
+
+use Norm;
+use super::DenseVector;
+
+impl<T, A> Norm for DenseVector<A>
+where
+    T: Copy + Signed + PartialOrd,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn l1(&self) -> Self::Scalar {
+        self.iter().fold(T::zero(), |sum, (_, value)| sum + Signed::abs(&value))
+    }
+
+    fn squared_l2(&self) -> Self::Scalar {
+        self.iter().fold(T::zero(), |sum, (_, value)| sum + (value * value))
+    }
+
+    fn linf(&self) -> Self::Scalar {
+        self.iter().fold(T::zero(), |max, (_, value)| {
+            let abs = Signed::abs(&value);
+            if abs > max { abs } else { max }
+        })
+    }
+
+    fn lp(&self, p: Self::Scalar) -> Self::Scalar
+    where
+        Self::Scalar: Real,
+    {
+        let sum = self.iter().fold(T::zero(), |sum, (_, value)| {
+            sum + Real::abs(value).powf(p)
+        });
+        sum.powf(p.recip())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use Error;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn l1() {
+        let subject = DenseVector::from([0.0, -3.0, 0.0, 4.0]);
+        expect!(subject.l1()).to(be_close_to(7.0));
+    }
+
+    #[test]
+    fn l2() {
+        let subject = DenseVector::from([0.0, -3.0, 0.0, 4.0]);
+        expect!(subject.squared_l2()).to(be_close_to(25.0));
+        expect!(subject.l2()).to(be_close_to(5.0));
+    }
+
+    #[test]
+    fn linf() {
+        let subject = DenseVector::from([0.0, -3.0, 0.0, 4.0]);
+        expect!(subject.linf()).to(be_close_to(4.0));
+    }
+
+    #[test]
+    fn lp() {
+        let subject = DenseVector::from([0.0, -3.0, 0.0, 4.0]);
+        expect!(subject.lp(1.0)).to(be_close_to(7.0));
+        expect!(subject.lp(3.0)).to(be_close_to(4.498));
+    }
+
+    #[test]
+    fn normalized() {
+        let subject = DenseVector::from([0.0, -3.0, 0.0, 4.0]);
+        let expected = DenseVector::from([0.0, -0.6, 0.0, 0.8]);
+        expect!(subject.normalized()).to(be_ok().value(expected));
+    }
+
+    #[test]
+    fn normalize_zero() {
+        let mut subject = DenseVector::from([0.0, 0.0]);
+        expect!(subject.normalize()).to(be_err().value(Error::ZeroVector));
+    }
+}
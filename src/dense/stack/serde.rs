@@ -0,0 +1,68 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use arrayvec::{Array, ArrayVec};
+
+use super::DenseVector;
+
+impl<T, A> Serialize for DenseVector<A>
+where
+    T: Serialize,
+    A: Array<Item = T>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.components[..].serialize(serializer)
+    }
+}
+
+impl<'de, T, A> Deserialize<'de> for DenseVector<A>
+where
+    T: Deserialize<'de>,
+    A: Array<Item = T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let components = ArrayVec::deserialize(deserializer)?;
+        Ok(DenseVector { components })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde_json;
+    use bincode;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn json() {
+        let subject = DenseVector::from([0.5, 0.0, 2.0]);
+        let json = serde_json::to_string(&subject).unwrap();
+        expect!(json.as_str()).to(be_equal_to("[0.5,0.0,2.0]"));
+        let result: DenseVector<[f64; 3]> = serde_json::from_str(&json).unwrap();
+        expect!(result).to(be_equal_to(subject));
+    }
+
+    #[test]
+    fn bincode() {
+        let subject = DenseVector::from([0.5, 0.0, 2.0]);
+        let bytes = bincode::serialize(&subject).unwrap();
+        let result: DenseVector<[f64; 3]> = bincode::deserialize(&bytes).unwrap();
+        expect!(result).to(be_equal_to(subject));
+    }
+
+    #[test]
+    fn json_capacity_exceeded() {
+        let result: Result<DenseVector<[f64; 3]>, _> = serde_json::from_str("[0.5,0.0,2.0,1.0]");
+        expect!(result.is_err()).to(be_true());
+    }
+}
@@ -0,0 +1,160 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::{Zero, Float};
+use arrayvec::Array;
+
+use {Statistics, sum, moments, extremum};
+use super::DenseVector;
+
+impl<T, A> Statistics for DenseVector<A>
+where
+    T: Copy + PartialOrd + Zero,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn nnz(&self) -> usize {
+        self.iter().filter(|&(_, value)| !value.is_zero()).count()
+    }
+
+    fn sum(&self) -> Self::Scalar {
+        sum(self.iter(), false)
+    }
+
+    fn nan_sum(&self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        sum(self.iter(), true)
+    }
+
+    fn mean(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        moments(self.iter(), 0, false).map(|(mean, _)| mean)
+    }
+
+    fn nan_mean(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        moments(self.iter(), 0, true).map(|(mean, _)| mean)
+    }
+
+    fn variance(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        moments(self.iter(), 0, false).map(|(_, variance)| variance)
+    }
+
+    fn nan_variance(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        moments(self.iter(), 0, true).map(|(_, variance)| variance)
+    }
+
+    fn min(&self) -> Option<Self::Scalar> {
+        extremum(self.iter(), None, false, |lhs, rhs| lhs < rhs).map(|(_, value)| value)
+    }
+
+    fn nan_min(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        extremum(self.iter(), None, true, |lhs, rhs| lhs < rhs).map(|(_, value)| value)
+    }
+
+    fn max(&self) -> Option<Self::Scalar> {
+        extremum(self.iter(), None, false, |lhs, rhs| lhs > rhs).map(|(_, value)| value)
+    }
+
+    fn nan_max(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        extremum(self.iter(), None, true, |lhs, rhs| lhs > rhs).map(|(_, value)| value)
+    }
+
+    fn argmin(&self) -> Option<usize> {
+        extremum(self.iter(), None, false, |lhs, rhs| lhs < rhs).map(|(index, _)| index)
+    }
+
+    fn nan_argmin(&self) -> Option<usize>
+    where
+        Self::Scalar: Float,
+    {
+        extremum(self.iter(), None, true, |lhs, rhs| lhs < rhs).map(|(index, _)| index)
+    }
+
+    fn argmax(&self) -> Option<usize> {
+        extremum(self.iter(), None, false, |lhs, rhs| lhs > rhs).map(|(index, _)| index)
+    }
+
+    fn nan_argmax(&self) -> Option<usize>
+    where
+        Self::Scalar: Float,
+    {
+        extremum(self.iter(), None, true, |lhs, rhs| lhs > rhs).map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::f64;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn sum() {
+        let subject = DenseVector::from([1.0, -2.0, 0.0, 4.0]);
+        expect!(subject.sum()).to(be_close_to(3.0));
+        expect!(subject.nnz()).to(be_equal_to(3));
+    }
+
+    #[test]
+    fn mean_variance() {
+        let subject = DenseVector::from([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        expect!(subject.mean().unwrap()).to(be_close_to(5.0));
+        expect!(subject.variance().unwrap()).to(be_close_to(4.0));
+    }
+
+    #[test]
+    fn min_max() {
+        let subject = DenseVector::from([3.0, -2.0, 5.0, -2.0, 5.0]);
+        expect!(subject.min()).to(be_some().value(-2.0));
+        expect!(subject.max()).to(be_some().value(5.0));
+        expect!(subject.argmin()).to(be_some().value(1));
+        expect!(subject.argmax()).to(be_some().value(2));
+    }
+
+    #[test]
+    fn integers() {
+        let subject = DenseVector::from([3, -2, 0, 5]);
+        expect!(subject.sum()).to(be_equal_to(6));
+        expect!(subject.min()).to(be_some().value(-2));
+        expect!(subject.argmax()).to(be_some().value(3));
+    }
+
+    #[test]
+    fn nan() {
+        let subject = DenseVector::from([3.0, f64::NAN, -2.0, 5.0]);
+        expect!(subject.sum().is_nan()).to(be_true());
+        expect!(subject.mean().unwrap().is_nan()).to(be_true());
+        expect!(subject.argmin()).to(be_some().value(1));
+        expect!(subject.max().unwrap().is_nan()).to(be_true());
+        expect!(subject.nan_sum()).to(be_close_to(6.0));
+        expect!(subject.nan_mean().unwrap()).to(be_close_to(2.0));
+        expect!(subject.nan_variance().unwrap()).to(be_close_to(26.0 / 3.0));
+        expect!(subject.nan_min()).to(be_some().value(-2.0));
+        expect!(subject.nan_max()).to(be_some().value(5.0));
+        expect!(subject.nan_argmin()).to(be_some().value(2));
+        expect!(subject.nan_argmax()).to(be_some().value(3));
+    }
+}
@@ -6,38 +6,114 @@ use std::ops::{Sub, SubAssign};
 
 use arrayvec::Array;
 
+use dense;
 use super::DenseVector;
 
-impl<T, A, V> Sub<V> for DenseVector<A>
+impl<T, A, B> Sub<DenseVector<B>> for DenseVector<A>
 where
-    T: SubAssign<T>,
+    T: Copy + SubAssign<T>,
     A: Array<Item = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
+    B: Array<Item = T>,
 {
     type Output = Self;
 
     #[inline]
-    fn sub(mut self, rhs: V) -> Self::Output {
+    fn sub(mut self, rhs: DenseVector<B>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A, B> Sub<&'a DenseVector<B>> for DenseVector<A>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &'a DenseVector<B>) -> Self::Output {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+impl<T, A, B> SubAssign<DenseVector<B>> for DenseVector<A>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: DenseVector<B>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+impl<'a, T, A, B> SubAssign<&'a DenseVector<B>> for DenseVector<A>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+    B: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: &'a DenseVector<B>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs -= rhs);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Sub<dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: dense::heap::DenseVector<T>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, A> Sub<&'a dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &'a dense::heap::DenseVector<T>) -> Self::Output {
         self.sub_assign(rhs);
         self
     }
 }
 
-impl<T, A, V> SubAssign<V> for DenseVector<A>
+#[cfg(feature = "std")]
+impl<T, A> SubAssign<dense::heap::DenseVector<T>> for DenseVector<A>
 where
-    T: SubAssign<T>,
+    T: Copy + SubAssign<T>,
     A: Array<Item = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator,
 {
     #[inline]
-    fn sub_assign(&mut self, rhs: V) {
-        let iter = rhs.into_iter();
-        debug_assert_eq!(self.len(), iter.len());
-        for (lhs, (_, rhs)) in self.components.iter_mut().zip(iter) {
-            *lhs -= rhs;
-        }
+    fn sub_assign(&mut self, rhs: dense::heap::DenseVector<T>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, A> SubAssign<&'a dense::heap::DenseVector<T>> for DenseVector<A>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: &'a dense::heap::DenseVector<T>) {
+        dense::zip_assign(self.as_mut_slice(), rhs.as_slice(), |lhs, rhs| *lhs -= rhs);
     }
 }
 
@@ -45,6 +121,10 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
+    use sparse::stack::SparseVector;
+
     use expectest::prelude::*;
 
     #[test]
@@ -74,4 +154,30 @@ mod test {
         result -= &other;
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn sub_assign_sparse() {
+        let subject = DenseVector::from([0.0, 0.5, 1.0, 2.0, 3.0]);
+        let other = SparseVector::try_from([(1, 0.5), (3, -1.0)]).unwrap();
+        let expected = DenseVector::from([0.0, 0.0, 1.0, 3.0, 3.0]);
+        let mut result = subject;
+        result -= &other;
+        expect!(result).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn sub_assign_dimension_mismatch() {
+        let mut subject = DenseVector::from([1.0, 2.0, 3.0]);
+        let other = DenseVector::from([1.0]);
+        subject -= &other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn sub_assign_sparse_dimension_mismatch() {
+        let mut subject = DenseVector::from([0.0, 0.5, 1.0]);
+        let other = SparseVector::try_from([(1, 0.5)]).unwrap().with_dim(6).unwrap();
+        subject -= &other;
+    }
 }
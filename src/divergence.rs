@@ -0,0 +1,283 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Divergences and distances between probability vectors.
+//!
+//! The functions expect their operands to be discrete probability distributions
+//! (or, where noted, histograms) with non-negative components. Components which
+//! are zero (or missing from a sparse vector) in both operands do not contribute.
+//! Logarithms are natural, i.e. results are given in nats.
+
+use num_traits::real::Real;
+
+use metric::PairwiseFold;
+
+/// Calculates `p * ln(p / q)`, defining `0 * ln(0 / q)` as zero.
+#[inline]
+fn relative_entropy<T>(p: T, q: T) -> T
+where
+    T: Real,
+{
+    if p.is_zero() {
+        T::zero()
+    } else {
+        p * (p / q).ln()
+    }
+}
+
+/// Calculates the shannon entropy of `p`.
+pub fn entropy<'a, V, T>(p: &'a V) -> T
+where
+    &'a V: IntoIterator<Item = (usize, T)>,
+    T: Real,
+{
+    p.into_iter().fold(T::zero(), |sum, (_, p)| {
+        if p.is_zero() { sum } else { sum - (p * p.ln()) }
+    })
+}
+
+/// Calculates the kullback-leibler divergence of `q` from `p`.
+///
+/// Is infinite if `q` is zero for any component for which `p` is not.
+pub fn kullback_leibler<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    p.fold_pairs(q, T::zero(), |sum, p, q| sum + relative_entropy(p, q))
+}
+
+/// Calculates the kullback-leibler divergence of `q` from `p`,
+/// raising the components of `q` to at least `epsilon`.
+///
+/// Unlike `kullback_leibler` this stays finite for any positive `epsilon`.
+pub fn kullback_leibler_smoothed<V, T>(p: &V, q: &V, epsilon: T) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    p.fold_pairs(q, T::zero(), |sum, p, q| sum + relative_entropy(p, q.max(epsilon)))
+}
+
+/// Calculates the symmetric kullback-leibler (jeffreys) divergence between `p` and `q`.
+pub fn jeffreys<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    p.fold_pairs(q, T::zero(), |sum, p, q| {
+        sum + relative_entropy(p, q) + relative_entropy(q, p)
+    })
+}
+
+/// Calculates the jensen-shannon divergence between `p` and `q`.
+///
+/// Unlike the kullback-leibler divergence this is symmetric and always finite.
+pub fn jensen_shannon<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    let two = T::one() + T::one();
+    let sum = p.fold_pairs(q, T::zero(), |sum, p, q| {
+        let m = (p + q) / two;
+        sum + relative_entropy(p, m) + relative_entropy(q, m)
+    });
+    sum / two
+}
+
+/// Calculates the bhattacharyya coefficient between `p` and `q`.
+pub fn bhattacharyya_coefficient<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    p.fold_pairs(q, T::zero(), |sum, p, q| sum + (p * q).sqrt())
+}
+
+/// Calculates the bhattacharyya distance between `p` and `q`.
+///
+/// Is infinite if `p` and `q` have disjoint supports.
+pub fn bhattacharyya<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    -bhattacharyya_coefficient(p, q).ln()
+}
+
+/// Calculates the hellinger distance between `p` and `q`.
+pub fn hellinger<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    let sum = p.fold_pairs(q, T::zero(), |sum, p, q| {
+        let delta = p.sqrt() - q.sqrt();
+        sum + (delta * delta)
+    });
+    (sum / (T::one() + T::one())).sqrt()
+}
+
+/// Calculates the chi-square distance (`Σ (p - q)² / (p + q)`) between `p` and `q`.
+pub fn chi_square<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    p.fold_pairs(q, T::zero(), |sum, p, q| {
+        let denominator = p + q;
+        if denominator.is_zero() {
+            sum
+        } else {
+            let delta = p - q;
+            sum + ((delta * delta) / denominator)
+        }
+    })
+}
+
+/// Calculates the intersection (`Σ min(p, q)`) between the histograms `p` and `q`.
+pub fn histogram_intersection<V, T>(p: &V, q: &V) -> T
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real,
+{
+    p.fold_pairs(q, T::zero(), |sum, p, q| sum + p.min(q))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+    use std::f64::consts::{LN_2, FRAC_1_SQRT_2};
+
+    use dense::heap::DenseVector;
+    use sparse::heap::SparseVector;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn entropy() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::entropy(&p)).to(be_close_to(LN_2));
+        expect!(super::entropy(&q)).to(be_close_to(1.0397));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::entropy(&p)).to(be_close_to(LN_2));
+        expect!(super::entropy(&q)).to(be_close_to(1.0397));
+    }
+
+    #[test]
+    fn kullback_leibler() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::kullback_leibler(&p, &q)).to(be_close_to(LN_2));
+        expect!(super::kullback_leibler(&q, &p).is_infinite()).to(be_true());
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::kullback_leibler(&p, &q)).to(be_close_to(LN_2));
+        expect!(super::kullback_leibler(&q, &p).is_infinite()).to(be_true());
+    }
+
+    #[test]
+    fn kullback_leibler_zero_probability() {
+        let p = DenseVector::from(vec![1.0, 0.0]);
+        let q = DenseVector::from(vec![0.5, 0.5]);
+        expect!(super::kullback_leibler(&p, &q)).to(be_close_to(LN_2));
+        expect!(super::kullback_leibler(&p, &p)).to(be_close_to(0.0));
+        let p = SparseVector::try_from(vec![(0, 1.0)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        expect!(super::kullback_leibler(&p, &q)).to(be_close_to(LN_2));
+        expect!(super::kullback_leibler(&p, &p)).to(be_close_to(0.0));
+    }
+
+    #[test]
+    fn kullback_leibler_smoothed() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::kullback_leibler_smoothed(&q, &p, 0.001)).to(be_close_to(2.7607));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::kullback_leibler_smoothed(&q, &p, 0.001)).to(be_close_to(2.7607));
+    }
+
+    #[test]
+    fn jeffreys() {
+        let p = DenseVector::from(vec![0.5, 0.5]);
+        let q = DenseVector::from(vec![0.25, 0.75]);
+        expect!(super::jeffreys(&p, &q)).to(be_close_to(0.2747));
+        expect!(super::jeffreys(&q, &p)).to(be_close_to(0.2747));
+    }
+
+    #[test]
+    fn jensen_shannon() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::jensen_shannon(&p, &q)).to(be_close_to(0.2158));
+        expect!(super::jensen_shannon(&q, &p)).to(be_close_to(0.2158));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::jensen_shannon(&p, &q)).to(be_close_to(0.2158));
+    }
+
+    #[test]
+    fn jensen_shannon_zero_probability() {
+        let p = DenseVector::from(vec![1.0, 0.0]);
+        let q = DenseVector::from(vec![0.0, 1.0]);
+        expect!(super::jensen_shannon(&p, &q)).to(be_close_to(LN_2));
+        let p = SparseVector::try_from(vec![(0, 1.0)]).unwrap();
+        let q = SparseVector::try_from(vec![(1, 1.0)]).unwrap();
+        expect!(super::jensen_shannon(&p, &q)).to(be_close_to(LN_2));
+    }
+
+    #[test]
+    fn bhattacharyya() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(bhattacharyya_coefficient(&p, &q)).to(be_close_to(FRAC_1_SQRT_2));
+        expect!(super::bhattacharyya(&p, &q)).to(be_close_to(0.3466));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::bhattacharyya(&p, &q)).to(be_close_to(0.3466));
+    }
+
+    #[test]
+    fn bhattacharyya_disjoint() {
+        let p = SparseVector::try_from(vec![(0, 1.0f64)]).unwrap();
+        let q = SparseVector::try_from(vec![(1, 1.0)]).unwrap();
+        expect!(super::bhattacharyya(&p, &q).is_infinite()).to(be_true());
+    }
+
+    #[test]
+    fn hellinger() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::hellinger(&p, &q)).to(be_close_to(0.5412));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::hellinger(&p, &q)).to(be_close_to(0.5412));
+    }
+
+    #[test]
+    fn chi_square() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::chi_square(&p, &q)).to(be_close_to(0.6667));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::chi_square(&p, &q)).to(be_close_to(0.6667));
+    }
+
+    #[test]
+    fn histogram_intersection() {
+        let p = DenseVector::from(vec![0.5, 0.5, 0.0]);
+        let q = DenseVector::from(vec![0.25, 0.25, 0.5]);
+        expect!(super::histogram_intersection(&p, &q)).to(be_close_to(0.5));
+        let p = SparseVector::try_from(vec![(0, 0.5), (1, 0.5)]).unwrap();
+        let q = SparseVector::try_from(vec![(0, 0.25), (1, 0.25), (2, 0.5)]).unwrap();
+        expect!(super::histogram_intersection(&p, &q)).to(be_close_to(0.5));
+    }
+}
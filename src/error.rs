@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! The crate's error type.
+
+use std::fmt;
+
+/// The error type for fallible vector operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A component's index is smaller than the index of its predecessor.
+    UnsortedIndex {
+        /// The offending index.
+        index: usize,
+    },
+    /// A component's index is equal to the index of its predecessor.
+    DuplicateIndex {
+        /// The offending index.
+        index: usize,
+    },
+    /// A component's index lies outside of the vector's dimension.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: usize,
+        /// The vector's dimension.
+        dim: usize,
+    },
+    /// The number of components exceeds a stack-allocated vector's capacity.
+    CapacityExceeded {
+        /// The vector's capacity.
+        capacity: usize,
+    },
+    /// The dimensions of two vectors do not match.
+    DimensionMismatch(DimensionMismatch),
+    /// A vector has zero norm, making it impossible to normalize.
+    ZeroVector,
+}
+
+/// The error type for operations on vectors of mismatching dimensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    /// The dimension of the left-hand side operand.
+    pub expected: usize,
+    /// The (minimum) dimension of the right-hand side operand.
+    pub found: usize,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnsortedIndex { index } => {
+                write!(f, "index {} is out of order", index)
+            },
+            Error::DuplicateIndex { index } => {
+                write!(f, "index {} occurs more than once", index)
+            },
+            Error::IndexOutOfBounds { index, dim } => {
+                write!(f, "index {} is out of bounds for dimension {}", index, dim)
+            },
+            Error::CapacityExceeded { capacity } => {
+                write!(f, "number of components exceeds capacity of {}", capacity)
+            },
+            Error::DimensionMismatch(ref error) => error.fmt(f),
+            Error::ZeroVector => {
+                write!(f, "vector has zero norm")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Error {}
+
+impl From<DimensionMismatch> for Error {
+    fn from(error: DimensionMismatch) -> Self {
+        Error::DimensionMismatch(error)
+    }
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "dimension mismatch: expected {}, found {}", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for DimensionMismatch {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn display() {
+        let subject = format!("{}", Error::DuplicateIndex { index: 5 });
+        let expected = "index 5 occurs more than once";
+        expect!(subject).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn display_dimension_mismatch() {
+        let error = Error::from(DimensionMismatch { expected: 3, found: 4 });
+        let subject = format!("{}", error);
+        let expected = "dimension mismatch: expected 3, found 4";
+        expect!(subject).to(be_equal_to(expected));
+    }
+}
@@ -0,0 +1,314 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Reading and writing vectors in the LIBSVM (SVMlight) format.
+//!
+//! Each line holds one record, consisting of a label, an optional query id
+//! and the record's non-zero features, followed by an optional comment:
+//!
+//! ```text
+//! <label> [qid:<id>] <index>:<value> <index>:<value> ... [# comment]
+//! ```
+//!
+//! Indices are one-based by default and must be strictly increasing.
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::io::{BufRead, Write};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use sparse::heap::SparseVector;
+use error::Error as VectorError;
+use super::{Error, parse};
+
+/// A single record of a LIBSVM file.
+#[derive(Clone, PartialEq)]
+pub struct Record<T> {
+    /// The record's label (or target value).
+    pub label: T,
+    /// The record's query id, if any.
+    pub qid: Option<u64>,
+    /// The record's features.
+    pub features: SparseVector<T>,
+}
+
+impl<T> fmt::Debug for Record<T>
+where
+    T: Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Record")
+            .field("label", &self.label)
+            .field("qid", &self.qid)
+            .field("features", &self.features)
+            .finish()
+    }
+}
+
+/// A reader streaming records from LIBSVM formatted input.
+#[derive(Debug)]
+pub struct Reader<R> {
+    reader: R,
+    zero_based: bool,
+    line: usize,
+    buffer: String,
+}
+
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    /// Creates a reader for one-based indices, reading from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self { reader, zero_based: false, line: 0, buffer: String::new() }
+    }
+
+    /// Makes `self` expect zero-based indices.
+    pub fn zero_based(mut self) -> Self {
+        self.zero_based = true;
+        self
+    }
+
+    /// Unwraps `self`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next record, skipping blank and comment-only lines.
+    ///
+    /// Returns `None` once the input is exhausted.
+    pub fn read_record<T>(&mut self) -> Result<Option<Record<T>>, Error>
+    where
+        T: FromStr,
+    {
+        loop {
+            self.buffer.clear();
+            if self.reader.read_line(&mut self.buffer)? == 0 {
+                return Ok(None);
+            }
+            self.line += 1;
+            match self.parse_line() {
+                Ok(Some(record)) => return Ok(Some(record)),
+                Ok(None) => continue,
+                Err(message) => return Err(Error::parse(self.line, message)),
+            }
+        }
+    }
+
+    /// Returns an iterator over the records of type `T` read by `self`.
+    pub fn records<T>(self) -> ReaderIter<R, T>
+    where
+        T: FromStr,
+    {
+        ReaderIter { reader: self, marker: PhantomData }
+    }
+
+    /// Parses the current line, returning `None` if it holds no record.
+    fn parse_line<T>(&self) -> Result<Option<Record<T>>, String>
+    where
+        T: FromStr,
+    {
+        let content = match self.buffer.find('#') {
+            Some(position) => &self.buffer[..position],
+            None => &self.buffer[..],
+        };
+        let mut tokens = content.split_whitespace().peekable();
+        let label = match tokens.next() {
+            Some(token) => parse(token, "label")?,
+            None => return Ok(None),
+        };
+        let qid = match tokens.peek().and_then(|token| token.strip_prefix("qid:")) {
+            Some(qid) => {
+                tokens.next();
+                Some(parse(qid, "query id")?)
+            },
+            None => None,
+        };
+        let mut components = Vec::new();
+        for token in tokens {
+            let (index, value) = match token.find(':') {
+                Some(position) => (&token[..position], &token[(position + 1)..]),
+                None => return Err(format!("invalid feature `{}`", token)),
+            };
+            let index: usize = parse(index, "index")?;
+            let index = if self.zero_based {
+                index
+            } else {
+                index.checked_sub(1).ok_or_else(|| "index 0 is invalid for one-based indices".to_string())?
+            };
+            components.push((index, parse(value, "value")?));
+        }
+        // Report offending indices as written, rather than zero-based:
+        let offset = if self.zero_based { 0 } else { 1 };
+        let features = SparseVector::try_from(components).map_err(|error| match error {
+            VectorError::UnsortedIndex { index } => VectorError::UnsortedIndex { index: index + offset },
+            VectorError::DuplicateIndex { index } => VectorError::DuplicateIndex { index: index + offset },
+            error => error,
+        }.to_string())?;
+        Ok(Some(Record { label, qid, features }))
+    }
+}
+
+/// An iterator over the records read by a `Reader`.
+#[derive(Debug)]
+pub struct ReaderIter<R, T> {
+    reader: Reader<R>,
+    marker: PhantomData<T>,
+}
+
+impl<R, T> Iterator for ReaderIter<R, T>
+where
+    R: BufRead,
+    T: FromStr,
+{
+    type Item = Result<Record<T>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_record().transpose()
+    }
+}
+
+/// A writer emitting records as LIBSVM formatted output.
+#[derive(Debug)]
+pub struct Writer<W> {
+    writer: W,
+    zero_based: bool,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a writer for one-based indices, writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, zero_based: false }
+    }
+
+    /// Makes `self` emit zero-based indices.
+    pub fn zero_based(mut self) -> Self {
+        self.zero_based = true;
+        self
+    }
+
+    /// Unwraps `self`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes `record` as a single line.
+    pub fn write_record<T>(&mut self, record: &Record<T>) -> Result<(), Error>
+    where
+        T: Copy + Display,
+    {
+        write!(self.writer, "{}", record.label)?;
+        if let Some(qid) = record.qid {
+            write!(self.writer, " qid:{}", qid)?;
+        }
+        let offset = if self.zero_based { 0 } else { 1 };
+        for (index, value) in record.features.iter() {
+            write!(self.writer, " {}:{}", index + offset, value)?;
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::Cursor;
+
+    use expectest::prelude::*;
+
+    fn records(input: &str) -> Vec<Result<Record<f64>, Error>> {
+        Reader::new(Cursor::new(input)).records().collect()
+    }
+
+    #[test]
+    fn read() {
+        let input = "+1 1:0.5 3:2\n-1 qid:7 2:1.5 # comment\n\n# comment only\n0.25\n";
+        let records: Vec<_> = records(input).into_iter().map(Result::unwrap).collect();
+        expect!(records.len()).to(be_equal_to(3));
+        expect!(records[0].label).to(be_equal_to(1.0));
+        expect!(records[0].qid).to(be_none());
+        let expected = SparseVector::try_from(vec![(0, 0.5), (2, 2.0)]).unwrap();
+        expect!(records[0].features.clone()).to(be_equal_to(expected));
+        expect!(records[1].label).to(be_equal_to(-1.0));
+        expect!(records[1].qid).to(be_some().value(7));
+        expect!(records[2].features.is_empty()).to(be_true());
+    }
+
+    #[test]
+    fn read_zero_based() {
+        let mut reader = Reader::new(Cursor::new("1 0:0.5 3:2\n")).zero_based();
+        let record: Record<f64> = reader.read_record().unwrap().unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.5), (3, 2.0)]).unwrap();
+        expect!(record.features).to(be_equal_to(expected));
+        expect!(reader.read_record::<f64>().unwrap()).to(be_none());
+    }
+
+    #[test]
+    fn read_zero_based_errors() {
+        let mut reader = Reader::new(Cursor::new("1 3:0.5 2:0.5\n")).zero_based();
+        match reader.read_record::<f64>() {
+            Err(Error::Parse { line, message }) => {
+                expect!(line).to(be_equal_to(1));
+                expect!(message.as_str()).to(be_equal_to("index 2 is out of order"));
+            },
+            _ => panic!("expected parse error"),
+        }
+    }
+
+    #[test]
+    fn read_errors() {
+        let cases = [
+            ("1 1:0.5\nx 1:0.5\n", 2, "invalid label `x`"),
+            ("1 0:0.5\n", 1, "index 0 is invalid for one-based indices"),
+            ("1 2:0.5 1:0.5\n", 1, "index 1 is out of order"),
+            ("1 1:0.5 1:0.25\n", 1, "index 1 occurs more than once"),
+            ("1 1=0.5\n", 1, "invalid feature `1=0.5`"),
+            ("1 a:0.5\n", 1, "invalid index `a`"),
+            ("1 1:b\n", 1, "invalid value `b`"),
+            ("1 qid:q 1:0.5\n", 1, "invalid query id `q`"),
+        ];
+        for &(input, expected_line, expected_message) in cases.iter() {
+            match records(input).into_iter().find(Result::is_err) {
+                Some(Err(Error::Parse { line, message })) => {
+                    expect!(line).to(be_equal_to(expected_line));
+                    expect!(message.as_str()).to(be_equal_to(expected_message));
+                },
+                _ => panic!("expected parse error for {:?}", input),
+            }
+        }
+    }
+
+    #[test]
+    fn write() {
+        let mut writer = Writer::new(Vec::new());
+        let features = SparseVector::try_from(vec![(0, 0.5), (2, 2.0)]).unwrap();
+        writer.write_record(&Record { label: 1.0, qid: None, features: features.clone() }).unwrap();
+        writer.write_record(&Record { label: -1.0, qid: Some(3), features }).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        expect!(output.as_str()).to(be_equal_to("1 1:0.5 3:2\n-1 qid:3 1:0.5 3:2\n"));
+    }
+
+    #[test]
+    fn round_trip_zero_based() {
+        let features = SparseVector::try_from(vec![(0, 0.5), (2, 2.0)]).unwrap();
+        let record = Record { label: 1.0, qid: Some(2), features };
+        let mut writer = Writer::new(Vec::new()).zero_based();
+        writer.write_record(&record).unwrap();
+        let output = writer.into_inner();
+        let mut reader = Reader::new(Cursor::new(output)).zero_based();
+        expect!(reader.read_record().unwrap()).to(be_some().value(record));
+    }
+}
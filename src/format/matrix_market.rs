@@ -0,0 +1,525 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Reading and writing collections of vectors in the Matrix Market format.
+//!
+//! Each vector corresponds to one row of the stored matrix. Sparse vectors
+//! use the coordinate format, dense vectors the (column-major) array format.
+//! Only `general` matrices with `real`, `integer` or `pattern` fields are supported.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::io::{BufRead, Write};
+use std::mem;
+use std::str::FromStr;
+
+use num_traits::One;
+
+use DimensionMismatch;
+use dense::heap::DenseVector;
+use sparse::heap::SparseVector;
+use super::{Error, parse};
+
+/// The type of a Matrix Market file's entries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field {
+    /// Floating point entries.
+    Real,
+    /// Integer entries.
+    Integer,
+    /// Entries without explicit values, all of which are one.
+    Pattern,
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Field::Real => write!(f, "real"),
+            Field::Integer => write!(f, "integer"),
+            Field::Pattern => write!(f, "pattern"),
+        }
+    }
+}
+
+/// The layout of a Matrix Market file's entries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Coordinate,
+    Array,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Format::Coordinate => write!(f, "coordinate"),
+            Format::Array => write!(f, "array"),
+        }
+    }
+}
+
+/// Reads the rows of a coordinate formatted matrix as sparse vectors
+/// of dimension matching the matrix's number of columns.
+///
+/// Entries may be given in any order, but each at most once.
+pub fn read_coordinate<R, T>(reader: R) -> Result<Vec<SparseVector<T>>, Error>
+where
+    R: BufRead,
+    T: FromStr + One,
+{
+    let mut lines = Lines::new(reader);
+    let field = lines.read_header(Format::Coordinate)?;
+    let size = lines.read_size(3)?;
+    let (rows, cols, count) = (size[0], size[1], size[2]);
+    lines.check_size(rows, mem::size_of::<SparseVector<T>>())?;
+    lines.check_size(count, mem::size_of::<(usize, T)>())?;
+    // Entries are gathered per row instead of preallocating rows, as the size line may be bogus:
+    let mut entries: BTreeMap<usize, BTreeMap<usize, T>> = BTreeMap::new();
+    for _ in 0..count {
+        lines.read_entry(if field == Field::Pattern { 2 } else { 3 }, count)?;
+        let tokens = lines.tokens();
+        let row = lines.parse_index(tokens[0], "row", rows)?;
+        let col = lines.parse_index(tokens[1], "column", cols)?;
+        let value = match field {
+            Field::Pattern => T::one(),
+            _ => lines.parse(tokens[2], "value")?,
+        };
+        if entries.entry(row).or_default().insert(col, value).is_some() {
+            return Err(lines.error(format!("duplicate entry ({}, {})", row + 1, col + 1)));
+        }
+    }
+    lines.expect_end(count)?;
+    let mut vectors = Vec::new();
+    lines.reserve(&mut vectors, rows)?;
+    for row in 0..rows {
+        let components = entries.remove(&row).map_or_else(Vec::new, |entries| entries.into_iter().collect());
+        let vector = SparseVector::from_sorted_unchecked(components)
+            .with_dim(cols)
+            .expect("indices should lie within dimension");
+        vectors.push(vector);
+    }
+    Ok(vectors)
+}
+
+/// Reads the rows of an array formatted matrix as dense vectors.
+pub fn read_array<R, T>(reader: R) -> Result<Vec<DenseVector<T>>, Error>
+where
+    R: BufRead,
+    T: FromStr,
+{
+    let mut lines = Lines::new(reader);
+    lines.read_header(Format::Array)?;
+    let size = lines.read_size(2)?;
+    let (rows, cols) = (size[0], size[1]);
+    let count = rows.checked_mul(cols).ok_or_else(|| lines.error("matrix size is too large"))?;
+    lines.check_size(rows, mem::size_of::<DenseVector<T>>())?;
+    lines.check_size(count, mem::size_of::<T>())?;
+    // Each row grows as its values are read, rather than trusting the size line up front:
+    let mut components: Vec<Vec<T>> = Vec::new();
+    for index in 0..count {
+        lines.read_entry(1, count)?;
+        let tokens = lines.tokens();
+        let value = lines.parse(tokens[0], "value")?;
+        match components.get_mut(index % rows) {
+            Some(row) => row.push(value),
+            None => components.push(vec![value]),
+        }
+    }
+    lines.expect_end(count)?;
+    // Rows of zero columns have no entries to be read from:
+    let missing = rows - components.len();
+    lines.reserve(&mut components, missing)?;
+    components.resize_with(rows, Vec::new);
+    Ok(components.into_iter().map(DenseVector::from).collect())
+}
+
+/// Writes `vectors` as the rows of a coordinate formatted matrix,
+/// whose number of columns is the largest of the vectors' dimensions.
+///
+/// Omits the vectors' values if `field` is `Field::Pattern`.
+///
+/// Values are written as displayed, regardless of `field`: writing `2.5`
+/// with `Field::Integer` produces a file that integer readers reject.
+pub fn write_coordinate<W, T>(mut writer: W, vectors: &[SparseVector<T>], field: Field) -> Result<(), Error>
+where
+    W: Write,
+    T: Copy + Display,
+{
+    let cols = vectors.iter().map(|vector| {
+        vector.dim().unwrap_or_else(|| vector.iter().last().map_or(0, |(index, _)| index + 1))
+    }).max().unwrap_or(0);
+    let count: usize = vectors.iter().map(SparseVector::len).sum();
+    writeln!(writer, "%%MatrixMarket matrix {} {} general", Format::Coordinate, field)?;
+    writeln!(writer, "{} {} {}", vectors.len(), cols, count)?;
+    for (row, vector) in vectors.iter().enumerate() {
+        for (col, value) in vector.iter() {
+            match field {
+                Field::Pattern => writeln!(writer, "{} {}", row + 1, col + 1)?,
+                _ => writeln!(writer, "{} {} {}", row + 1, col + 1, value)?,
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes `vectors` as the rows of an array formatted matrix.
+///
+/// Values are written as displayed, regardless of `field`: writing `2.5`
+/// with `Field::Integer` produces a file that integer readers reject.
+///
+/// Fails with `Error::Invalid` if `field` is `Field::Pattern`, or if the vectors differ in length.
+pub fn write_array<W, T>(mut writer: W, vectors: &[DenseVector<T>], field: Field) -> Result<(), Error>
+where
+    W: Write,
+    T: Copy + Display,
+{
+    if field == Field::Pattern {
+        return Err(Error::Invalid("array format does not support pattern field".to_owned()));
+    }
+    let cols = vectors.first().map_or(0, DenseVector::len);
+    if let Some(vector) = vectors.iter().find(|vector| vector.len() != cols) {
+        let mismatch = DimensionMismatch { expected: cols, found: vector.len() };
+        return Err(Error::Invalid(mismatch.to_string()));
+    }
+    writeln!(writer, "%%MatrixMarket matrix {} {} general", Format::Array, field)?;
+    writeln!(writer, "{} {}", vectors.len(), cols)?;
+    for col in 0..cols {
+        for vector in vectors {
+            writeln!(writer, "{}", vector.as_slice()[col])?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// A line-numbering reader of Matrix Market formatted input.
+struct Lines<R> {
+    reader: R,
+    line: usize,
+    buffer: String,
+}
+
+impl<R> Lines<R>
+where
+    R: BufRead,
+{
+    fn new(reader: R) -> Self {
+        Self { reader, line: 0, buffer: String::new() }
+    }
+
+    /// Creates a parse error for the current line.
+    fn error<M>(&self, message: M) -> Error
+    where
+        M: Into<String>,
+    {
+        Error::parse(self.line, message)
+    }
+
+    /// Parses `token`, describing it as `what` if it is invalid.
+    fn parse<T>(&self, token: &str, what: &str) -> Result<T, Error>
+    where
+        T: FromStr,
+    {
+        parse(token, what).map_err(|message| self.error(message))
+    }
+
+    /// Parses the one-based `token` into a zero-based index smaller than `len`.
+    fn parse_index(&self, token: &str, what: &str, len: usize) -> Result<usize, Error> {
+        let index: usize = self.parse(token, what)?;
+        if index == 0 || index > len {
+            return Err(self.error(format!("{} {} is out of range 1..={}", what, index, len)));
+        }
+        Ok(index - 1)
+    }
+
+    /// Checks that `len` values of `size` bytes each could fit in memory.
+    fn check_size(&self, len: usize, size: usize) -> Result<(), Error> {
+        match len.checked_mul(size) {
+            Some(bytes) if bytes <= isize::MAX as usize => Ok(()),
+            _ => Err(self.error("matrix size is too large")),
+        }
+    }
+
+    /// Reserves capacity for `additional` more elements in `vec`,
+    /// failing instead of aborting if they cannot be allocated.
+    fn reserve<V>(&self, vec: &mut Vec<V>, additional: usize) -> Result<(), Error> {
+        vec.try_reserve_exact(additional).map_err(|_| self.error("matrix size is too large"))
+    }
+
+    /// Reads the next line, returning `false` once the input is exhausted.
+    fn read_line(&mut self) -> Result<bool, Error> {
+        self.buffer.clear();
+        if self.reader.read_line(&mut self.buffer)? == 0 {
+            return Ok(false);
+        }
+        self.line += 1;
+        Ok(true)
+    }
+
+    /// Reads the next line that is neither blank nor a comment,
+    /// returning `false` once the input is exhausted.
+    fn read_content(&mut self) -> Result<bool, Error> {
+        while self.read_line()? {
+            let content = self.buffer.trim();
+            if !content.is_empty() && !content.starts_with('%') {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Reads and validates the header line, returning the file's field.
+    fn read_header(&mut self, expected: Format) -> Result<Field, Error> {
+        if !self.read_line()? {
+            return Err(self.error("missing MatrixMarket header"));
+        }
+        let header = self.buffer.to_lowercase();
+        let tokens: Vec<&str> = header.split_whitespace().collect();
+        if tokens.first() != Some(&"%%matrixmarket") {
+            return Err(self.error("missing MatrixMarket header"));
+        }
+        if tokens.len() != 5 {
+            return Err(self.error("malformed MatrixMarket header"));
+        }
+        if tokens[1] != "matrix" {
+            return Err(self.error(format!("unsupported object `{}`", tokens[1])));
+        }
+        let format = match tokens[2] {
+            "coordinate" => Format::Coordinate,
+            "array" => Format::Array,
+            format => return Err(self.error(format!("unsupported format `{}`", format))),
+        };
+        if format != expected {
+            return Err(self.error(format!("expected {} format, found {}", expected, format)));
+        }
+        let field = match tokens[3] {
+            "real" => Field::Real,
+            "integer" => Field::Integer,
+            "pattern" if format == Format::Coordinate => Field::Pattern,
+            field => return Err(self.error(format!("unsupported field `{}`", field))),
+        };
+        if tokens[4] != "general" {
+            return Err(self.error(format!("unsupported symmetry `{}`", tokens[4])));
+        }
+        Ok(field)
+    }
+
+    /// Reads the size line, consisting of `len` numbers.
+    fn read_size(&mut self, len: usize) -> Result<Vec<usize>, Error> {
+        if !self.read_content()? {
+            return Err(self.error("missing size line"));
+        }
+        let size = self.tokens();
+        if size.len() != len {
+            return Err(self.error(format!("expected {} numbers in size line, found {}", len, size.len())));
+        }
+        size.iter().map(|token| self.parse(token, "size")).collect()
+    }
+
+    /// The whitespace-separated tokens of the current line.
+    fn tokens(&self) -> Vec<&str> {
+        self.buffer.split_whitespace().collect()
+    }
+
+    /// Reads the next entry, consisting of `len` tokens, out of `count` entries.
+    fn read_entry(&mut self, len: usize, count: usize) -> Result<(), Error> {
+        if !self.read_content()? {
+            return Err(self.error(format!("expected {} entries, found fewer", count)));
+        }
+        let found = self.buffer.split_whitespace().count();
+        if found != len {
+            return Err(self.error(format!("expected {} values in entry, found {}", len, found)));
+        }
+        Ok(())
+    }
+
+    /// Checks that no entries follow the expected `count` entries.
+    fn expect_end(&mut self, count: usize) -> Result<(), Error> {
+        if self.read_content()? {
+            return Err(self.error(format!("expected {} entries, found more", count)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+    use std::io::Cursor;
+
+    use expectest::prelude::*;
+
+    fn parse_error<T>(result: Result<T, Error>) -> (usize, String) {
+        match result {
+            Err(Error::Parse { line, message }) => (line, message),
+            _ => panic!("expected parse error"),
+        }
+    }
+
+    fn invalid_error<T>(result: Result<T, Error>) -> String {
+        match result {
+            Err(Error::Invalid(message)) => message,
+            _ => panic!("expected invalid error"),
+        }
+    }
+
+    #[test]
+    fn read_coordinate_real() {
+        let input = "%%MatrixMarket matrix coordinate real general\n% comment\n\n3 4 3\n2 4 1.5\n1 2 0.5\n2 1 -2\n";
+        let vectors: Vec<SparseVector<f64>> = read_coordinate(Cursor::new(input)).unwrap();
+        let expected = vec![
+            SparseVector::try_from(vec![(1, 0.5)]).unwrap().with_dim(4).unwrap(),
+            SparseVector::try_from(vec![(0, -2.0), (3, 1.5)]).unwrap().with_dim(4).unwrap(),
+            SparseVector::try_from(vec![]).unwrap().with_dim(4).unwrap(),
+        ];
+        expect!(vectors).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn read_coordinate_pattern() {
+        let input = "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n";
+        let vectors: Vec<SparseVector<i32>> = read_coordinate(Cursor::new(input)).unwrap();
+        let expected = vec![
+            SparseVector::try_from(vec![(2, 1)]).unwrap().with_dim(3).unwrap(),
+            SparseVector::try_from(vec![(0, 1)]).unwrap().with_dim(3).unwrap(),
+        ];
+        expect!(vectors).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn read_coordinate_errors() {
+        let cases = [
+            ("", 0, "missing MatrixMarket header"),
+            ("3 4 3\n", 1, "missing MatrixMarket header"),
+            ("%%MatrixMarket matrix coordinate complex general\n", 1, "unsupported field `complex`"),
+            ("%%MatrixMarket matrix coordinate real symmetric\n", 1, "unsupported symmetry `symmetric`"),
+            ("%%MatrixMarket matrix array real general\n", 1, "expected coordinate format, found array"),
+            ("%%MatrixMarket matrix coordinate real general\n2 2\n", 2, "expected 3 numbers in size line, found 2"),
+            ("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n", 3, "row 3 is out of range 1..=2"),
+            ("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 x\n", 3, "invalid value `x`"),
+            ("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n1 1 2\n", 4, "duplicate entry (1, 1)"),
+            ("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n", 3, "expected 2 entries, found fewer"),
+            ("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1\n2 2 1\n", 4, "expected 1 entries, found more"),
+            ("%%MatrixMarket matrix coordinate real general\n2305843009213693952 1 0\n", 2, "matrix size is too large"),
+            ("%%MatrixMarket matrix coordinate real general\n1 1 2305843009213693952\n", 2, "matrix size is too large"),
+            ("%%MatrixMarket matrix coordinate real general\n1 100000000000 100000000000\n1 1 1\n", 3, "expected 100000000000 entries, found fewer"),
+        ];
+        for &(input, expected_line, expected_message) in cases.iter() {
+            let (line, message) = parse_error(read_coordinate::<_, f64>(Cursor::new(input)));
+            expect!(line).to(be_equal_to(expected_line));
+            expect!(message.as_str()).to(be_equal_to(expected_message));
+        }
+    }
+
+    #[test]
+    fn read_array() {
+        let input = "%%MatrixMarket matrix array real general\n% column-major\n2 3\n1\n4\n2\n5\n3\n6\n";
+        let vectors: Vec<DenseVector<f64>> = super::read_array(Cursor::new(input)).unwrap();
+        let expected = vec![
+            DenseVector::from(vec![1.0, 2.0, 3.0]),
+            DenseVector::from(vec![4.0, 5.0, 6.0]),
+        ];
+        expect!(vectors).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn read_array_errors() {
+        let cases = [
+            ("%%MatrixMarket matrix array pattern general\n", 1, "unsupported field `pattern`"),
+            ("%%MatrixMarket matrix array real general\n1 2\n1\n", 3, "expected 2 entries, found fewer"),
+            ("%%MatrixMarket matrix array real general\n1 1\n1 2\n", 3, "expected 1 values in entry, found 2"),
+            ("%%MatrixMarket matrix array real general\n1 100000000000\n1\n", 3, "expected 100000000000 entries, found fewer"),
+            ("%%MatrixMarket matrix array real general\n4294967296 4294967296\n", 2, "matrix size is too large"),
+            ("%%MatrixMarket matrix array real general\n18446744073709551615 2\n", 2, "matrix size is too large"),
+        ];
+        for &(input, expected_line, expected_message) in cases.iter() {
+            let (line, message) = parse_error(super::read_array::<_, f64>(Cursor::new(input)));
+            expect!(line).to(be_equal_to(expected_line));
+            expect!(message.as_str()).to(be_equal_to(expected_message));
+        }
+    }
+
+    #[test]
+    fn read_array_zero_columns() {
+        let input = "%%MatrixMarket matrix array real general\n2 0\n";
+        let vectors: Vec<DenseVector<f64>> = super::read_array(Cursor::new(input)).unwrap();
+        expect!(vectors).to(be_equal_to(vec![DenseVector::from(vec![]), DenseVector::from(vec![])]));
+    }
+
+    #[test]
+    fn write_coordinate() {
+        let vectors = vec![
+            SparseVector::try_from(vec![(1, 0.5)]).unwrap(),
+            SparseVector::try_from(vec![(0, -2.0), (3, 1.5)]).unwrap(),
+        ];
+        let mut output = Vec::new();
+        super::write_coordinate(&mut output, &vectors, Field::Real).unwrap();
+        let expected = "%%MatrixMarket matrix coordinate real general\n2 4 3\n1 2 0.5\n2 1 -2\n2 4 1.5\n";
+        expect!(String::from_utf8(output).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn write_coordinate_pattern() {
+        let vectors = vec![SparseVector::try_from(vec![(1, 1)]).unwrap().with_dim(5).unwrap()];
+        let mut output = Vec::new();
+        super::write_coordinate(&mut output, &vectors, Field::Pattern).unwrap();
+        let expected = "%%MatrixMarket matrix coordinate pattern general\n1 5 1\n1 2\n";
+        expect!(String::from_utf8(output).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn write_coordinate_field_mismatch() {
+        let vectors = vec![SparseVector::try_from(vec![(0, 2.5)]).unwrap()];
+        let mut output = Vec::new();
+        super::write_coordinate(&mut output, &vectors, Field::Integer).unwrap();
+        let expected = "%%MatrixMarket matrix coordinate integer general\n1 1 1\n1 1 2.5\n";
+        expect!(String::from_utf8(output.clone()).unwrap()).to(be_equal_to(expected));
+        let (line, message) = parse_error(read_coordinate::<_, i32>(Cursor::new(output)));
+        expect!(line).to(be_equal_to(3));
+        expect!(message.as_str()).to(be_equal_to("invalid value `2.5`"));
+    }
+
+    #[test]
+    fn round_trip_coordinate() {
+        let vectors = vec![
+            SparseVector::try_from(vec![(1, 0.5)]).unwrap().with_dim(4).unwrap(),
+            SparseVector::try_from(vec![]).unwrap().with_dim(4).unwrap(),
+            SparseVector::try_from(vec![(0, -2.0), (3, 1.5)]).unwrap().with_dim(4).unwrap(),
+        ];
+        let mut output = Vec::new();
+        super::write_coordinate(&mut output, &vectors, Field::Real).unwrap();
+        let result: Vec<SparseVector<f64>> = read_coordinate(Cursor::new(output)).unwrap();
+        expect!(result).to(be_equal_to(vectors));
+    }
+
+    #[test]
+    fn round_trip_array() {
+        let vectors = vec![
+            DenseVector::from(vec![1, 2, 3]),
+            DenseVector::from(vec![4, 5, 6]),
+        ];
+        let mut output = Vec::new();
+        super::write_array(&mut output, &vectors, Field::Integer).unwrap();
+        let result: Vec<DenseVector<i32>> = super::read_array(Cursor::new(output)).unwrap();
+        expect!(result).to(be_equal_to(vectors));
+    }
+
+    #[test]
+    fn write_array_dimension_mismatch() {
+        let vectors = vec![DenseVector::from(vec![1.0, 2.0]), DenseVector::from(vec![3.0])];
+        let mut output = Vec::new();
+        let message = invalid_error(super::write_array(&mut output, &vectors, Field::Real));
+        expect!(message.starts_with("dimension mismatch")).to(be_true());
+        expect!(output.iter()).to(be_empty());
+    }
+
+    #[test]
+    fn write_array_pattern() {
+        let vectors = vec![DenseVector::from(vec![1.0, 2.0])];
+        let message = invalid_error(super::write_array(Vec::new(), &vectors, Field::Pattern));
+        expect!(message).to(be_equal_to("array format does not support pattern field"));
+    }
+}
@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Readers and writers for common vector file formats.
+
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+pub mod libsvm;
+pub mod matrix_market;
+
+/// The error type for reading and writing vector file formats.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading from or writing to the underlying stream failed.
+    Io(io::Error),
+    /// A line of the input is malformed.
+    Parse {
+        /// The (one-based) number of the offending line.
+        line: usize,
+        /// A description of the problem.
+        message: String,
+    },
+    /// The vectors cannot be written in the requested format.
+    Invalid(String),
+}
+
+impl Error {
+    fn parse<M>(line: usize, message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Error::Parse { line, message: message.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref error) => error.fmt(f),
+            Error::Parse { line, ref message } => {
+                write!(f, "line {}: {}", line, message)
+            },
+            Error::Invalid(ref message) => message.fmt(f),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn (::std::error::Error) + 'static)> {
+        match *self {
+            Error::Io(ref error) => Some(error),
+            Error::Parse { .. } | Error::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// Parses `token`, describing it as `what` if it is invalid.
+fn parse<T>(token: &str, what: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    token.parse().map_err(|_| format!("invalid {} `{}`", what, token))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn display_parse() {
+        let subject = format!("{}", Error::parse(3, "invalid label `x`"));
+        expect!(subject).to(be_equal_to("line 3: invalid label `x`"));
+    }
+}
@@ -26,13 +26,35 @@ extern crate expectest;
 extern crate num_traits;
 extern crate ordered_iter;
 extern crate arrayvec;
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(all(test, feature = "serde"))]
+extern crate bincode;
+
+#[macro_use]
+mod macros;
+mod error;
 
 pub mod dense;
 pub mod sparse;
+pub mod metric;
+pub mod divergence;
+#[cfg(feature = "std")]
+pub mod format;
+
+pub use error::{Error, DimensionMismatch};
 
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
 
-use num_traits::{MulAdd, MulAddAssign, real::Real};
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+use num_traits::{Zero, One, NumCast, Float, Signed, MulAdd, MulAddAssign, real::Real};
+#[cfg(feature = "std")]
+use ordered_iter::OrderedMapIterator;
 
 /// The crate's prelude
 pub mod prelude {
@@ -40,8 +62,14 @@ pub mod prelude {
         VectorOps, VectorAssignOps,
         Vector, VectorRef,
         VectorAssign, VectorAssignRef,
-        Dot, Distance
+        CheckedVectorOps,
+        Dot, Distance, Norm, Cosine,
+        SetSimilarity, Correlation,
+        Hadamard, ElementwiseMath,
+        Statistics
     };
+    #[cfg(feature = "std")]
+    pub use super::Selection;
 }
 
 /// The trait for vector types implementing basic numeric operations.
@@ -109,28 +137,711 @@ where
     T: VectorAssign<S> + for<'a> VectorAssignOps<&'a T, S>
 {}
 
+/// The trait for types supporting element-wise (hadamard) multiplication and division
+///
+/// Multiplying or dividing a sparse vector preserves its sparsity:
+/// components missing from it stay zero.
+pub trait Hadamard<Rhs = Self>: Sized {
+    /// Multiplies each of `self`'s components by the corresponding component of `rhs`.
+    fn hadamard_mul_assign(&mut self, rhs: &Rhs);
+
+    /// Divides each of `self`'s components by the corresponding component of `rhs`.
+    fn hadamard_div_assign(&mut self, rhs: &Rhs);
+
+    /// Calculates the element-wise product of `self` and `rhs`.
+    fn hadamard_mul(mut self, rhs: &Rhs) -> Self {
+        self.hadamard_mul_assign(rhs);
+        self
+    }
+
+    /// Calculates the element-wise quotient of `self` and `rhs`.
+    fn hadamard_div(mut self, rhs: &Rhs) -> Self {
+        self.hadamard_div_assign(rhs);
+        self
+    }
+}
+
+/// The trait for vector types supporting element-wise math functions
+///
+/// Only implemented by dense vectors, as functions like `exp` and `ln`
+/// do not map zero to zero. Sparse vectors instead provide `abs`, `sqrt`
+/// and `signum` as inherent methods, which preserve their sparsity.
+pub trait ElementwiseMath: Sized {
+    /// The type of the vector's scalar components
+    type Scalar;
+
+    /// Calculates the absolute value of each of `self`'s components.
+    fn abs(self) -> Self;
+
+    /// Calculates the square root of each of `self`'s components.
+    fn sqrt(self) -> Self;
+
+    /// Calculates the exponential of each of `self`'s components.
+    fn exp(self) -> Self;
+
+    /// Calculates the natural logarithm of each of `self`'s components.
+    fn ln(self) -> Self;
+
+    /// Calculates the sign of each of `self`'s components.
+    fn signum(self) -> Self;
+
+    /// Restricts each of `self`'s components to the interval `[min, max]`.
+    ///
+    /// Panics if `min > max`.
+    fn clamp(self, min: Self::Scalar, max: Self::Scalar) -> Self;
+}
+
 /// The trait for types supporting the calculation of the dot product
-pub trait Dot: Sized {
+pub trait Dot<Rhs = Self>: Sized {
     /// The scalar type returned by `self`'s dot product
     type Scalar;
 
     /// Calculates the dot-product between `self` and `rhs`.
-    fn dot(&self, rhs: &Self) -> Self::Scalar;
+    fn dot(&self, rhs: &Rhs) -> Self::Scalar;
 }
 
 /// The trait for types supporting the calculation of distance
-pub trait Distance: Sized {
+pub trait Distance<Rhs = Self>: Sized {
     /// The scalar type returned by `self`'s distance
     type Scalar;
 
     /// Calculates the squared euclidian distance between `self` and `rhs`.
-    fn squared_distance(&self, rhs: &Self) -> Self::Scalar;
+    fn squared_distance(&self, rhs: &Rhs) -> Self::Scalar;
+
+    /// Calculates the squared euclidian distance between `self` and `rhs`,
+    /// if it does not exceed `bound`.
+    ///
+    /// Implementations may abandon the calculation as soon as
+    /// the partial distance exceeds `bound`.
+    fn squared_distance_bounded(&self, rhs: &Rhs, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        Self::Scalar: PartialOrd,
+    {
+        bounded(self.squared_distance(rhs), bound)
+    }
 
     /// Calculates the euclidian distance between `self` and `rhs`.
-    fn distance(&self, rhs: &Self) -> Self::Scalar
+    fn distance(&self, rhs: &Rhs) -> Self::Scalar
     where
         Self::Scalar: Real,
     {
         self.squared_distance(rhs).sqrt()
     }
 }
+
+/// The trait for types supporting the calculation of cosine similarity
+pub trait Cosine<Rhs = Self>: Sized {
+    /// The scalar type returned by `self`'s cosine similarity
+    type Scalar;
+
+    /// Calculates the cosine similarity between `self` and `rhs`.
+    ///
+    /// Returns zero if either of `self` and `rhs` is a zero vector.
+    fn cosine_similarity(&self, rhs: &Rhs) -> Self::Scalar;
+
+    /// Calculates the cosine distance (`1 - similarity`) between `self` and `rhs`.
+    fn cosine_distance(&self, rhs: &Rhs) -> Self::Scalar
+    where
+        Self::Scalar: Real,
+    {
+        Self::Scalar::one() - self.cosine_similarity(rhs)
+    }
+}
+
+/// The trait for types supporting the calculation of correlation coefficients
+///
+/// Sparse vectors take their implicit zero components into account,
+/// up to their dimension or, if unknown, up to their last non-zero component.
+pub trait Correlation<Rhs = Self>: Sized {
+    /// The scalar type returned by `self`'s correlation coefficients
+    type Scalar;
+
+    /// Calculates the pearson correlation coefficient between `self` and `rhs`.
+    ///
+    /// Returns zero if either of `self` and `rhs` has zero variance.
+    fn pearson(&self, rhs: &Rhs) -> Self::Scalar;
+
+    /// Calculates the spearman rank correlation coefficient between `self` and `rhs`,
+    /// assigning tied components their average rank.
+    ///
+    /// Returns zero if either of `self` and `rhs` has zero variance.
+    #[cfg(feature = "std")]
+    fn spearman(&self, rhs: &Rhs) -> Self::Scalar;
+
+    /// Calculates the correlation distance (`1 - pearson`) between `self` and `rhs`.
+    fn correlation_distance(&self, rhs: &Rhs) -> Self::Scalar
+    where
+        Self::Scalar: Real,
+    {
+        Self::Scalar::one() - self.pearson(rhs)
+    }
+}
+
+/// The trait for types supporting set-based similarities,
+/// treating a vector as the set of its non-zero components' indices
+///
+/// Two empty sets have a similarity of one,
+/// an empty and a non-empty set have a similarity of zero.
+pub trait SetSimilarity: Sized {
+    /// The scalar type returned by `self`'s weighted similarities
+    type Scalar;
+
+    /// The number of non-zero components in `self`
+    fn support_len(&self) -> usize;
+
+    /// The number of indices of non-zero components shared by `self` and `rhs`
+    fn intersection_len(&self, rhs: &Self) -> usize;
+
+    /// Calculates the weighted jaccard similarity (`Σ min / Σ max`)
+    /// between `self` and `rhs`.
+    ///
+    /// Expects the components of `self` and `rhs` to be non-negative.
+    fn weighted_jaccard(&self, rhs: &Self) -> Self::Scalar;
+
+    /// Calculates the jaccard similarity (`|A ∩ B| / |A ∪ B|`) between `self` and `rhs`.
+    fn jaccard(&self, rhs: &Self) -> f64 {
+        let intersection = self.intersection_len(rhs);
+        let union = self.support_len() + rhs.support_len() - intersection;
+        ratio(intersection, union)
+    }
+
+    /// Calculates the sørensen-dice coefficient (`2|A ∩ B| / (|A| + |B|)`)
+    /// between `self` and `rhs`.
+    fn dice(&self, rhs: &Self) -> f64 {
+        let intersection = self.intersection_len(rhs);
+        ratio(2 * intersection, self.support_len() + rhs.support_len())
+    }
+
+    /// Calculates the overlap coefficient (`|A ∩ B| / min(|A|, |B|)`)
+    /// between `self` and `rhs`.
+    fn overlap(&self, rhs: &Self) -> f64 {
+        let (lhs_len, rhs_len) = (self.support_len(), rhs.support_len());
+        if lhs_len.min(rhs_len) == 0 {
+            return if lhs_len == rhs_len { 1.0 } else { 0.0 };
+        }
+        ratio(self.intersection_len(rhs), lhs_len.min(rhs_len))
+    }
+
+    /// Calculates the hamming distance (`|A △ B|`) between
+    /// the supports of `self` and `rhs`.
+    fn hamming(&self, rhs: &Self) -> usize {
+        let intersection = self.intersection_len(rhs);
+        self.support_len() + rhs.support_len() - (2 * intersection)
+    }
+}
+
+/// Returns `value`, if it does not exceed `bound`.
+#[inline]
+fn bounded<T>(value: T, bound: T) -> Option<T>
+where
+    T: PartialOrd,
+{
+    if value > bound { None } else { Some(value) }
+}
+
+/// Calculates `numerator / denominator`, defining `0 / 0` as one.
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        1.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Calculates the cosine similarity from a dot product and the two squared norms,
+/// treating zero vectors as orthogonal to any vector.
+fn cosine_similarity<T>(dot: T, lhs_squared_l2: T, rhs_squared_l2: T) -> T
+where
+    T: Real,
+{
+    let norms = lhs_squared_l2.sqrt() * rhs_squared_l2.sqrt();
+    if norms.is_zero() {
+        return T::zero();
+    }
+    (dot / norms).max(-T::one()).min(T::one())
+}
+
+/// Calculates the pearson correlation coefficient of `n` pairs of samples,
+/// given all pairs which are not both zero.
+///
+/// Accumulates centered co-moments (Welford), merged with the omitted
+/// zero pairs, which stays accurate for samples far from zero.
+fn pearson<T, I>(n: usize, pairs: I) -> T
+where
+    T: Real,
+    I: IntoIterator<Item = (T, T)>,
+{
+    let zero = T::zero();
+    let cast = |value: usize| -> T { NumCast::from(value).expect("count should be representable") };
+    let (mut count, mut mean_x, mut mean_y) = (0, zero, zero);
+    let (mut m2_x, mut m2_y, mut c_xy) = (zero, zero, zero);
+    for (x, y) in pairs {
+        count += 1;
+        let delta_x = x - mean_x;
+        let delta_y = y - mean_y;
+        mean_x = mean_x + (delta_x / cast(count));
+        mean_y = mean_y + (delta_y / cast(count));
+        m2_x = m2_x + (delta_x * (x - mean_x));
+        m2_y = m2_y + (delta_y * (y - mean_y));
+        c_xy = c_xy + (delta_x * (y - mean_y));
+    }
+    let zeros = n.checked_sub(count).expect("pairs should not exceed the number of samples");
+    if n == 0 {
+        return zero;
+    }
+    let weight = cast(count) * cast(zeros) / cast(n);
+    let (delta_x, delta_y) = (zero - mean_x, zero - mean_y);
+    let m2_x = m2_x + (delta_x * delta_x * weight);
+    let m2_y = m2_y + (delta_y * delta_y * weight);
+    let c_xy = c_xy + (delta_x * delta_y * weight);
+    if m2_x <= zero || m2_y <= zero {
+        return zero;
+    }
+    let correlation = c_xy / (m2_x.sqrt() * m2_y.sqrt());
+    correlation.max(-T::one()).min(T::one())
+}
+
+/// Ranks the components of a vector of dimension `dim`, given its non-zero components,
+/// assigning tied components their average rank.
+///
+/// Shifts the ranks such that zero components have a rank of zero,
+/// allowing them to be omitted from the result.
+#[cfg(feature = "std")]
+fn shifted_ranks<T, I>(components: I, dim: usize) -> Vec<(usize, T)>
+where
+    T: Real,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    let zero = T::zero();
+    let two = T::one() + T::one();
+    let cast = |value: usize| -> T { NumCast::from(value).expect("rank should be representable") };
+    let mut sorted: Vec<(usize, T)> = components.into_iter().filter(|&(_, value)| !value.is_zero()).collect();
+    sorted.sort_by(|lhs, rhs| lhs.1.partial_cmp(&rhs.1).unwrap_or(Ordering::Equal));
+    let negatives = sorted.iter().take_while(|&&(_, value)| value < zero).count();
+    let zeros = dim.checked_sub(sorted.len()).expect("non-zero components should not exceed the dimension");
+    let zero_rank = cast(negatives) + ((cast(zeros) + T::one()) / two);
+    let mut ranks = Vec::with_capacity(sorted.len());
+    let mut start = 0;
+    while start < sorted.len() {
+        let value = sorted[start].1;
+        let ties = sorted[(start + 1)..].iter().take_while(|&&(_, other)| other == value).count();
+        let end = start + 1 + ties;
+        let offset = if value > zero { zeros } else { 0 };
+        let rank = (cast(offset + start + 1) + cast(offset + end)) / two;
+        for &(index, _) in &sorted[start..end] {
+            ranks.push((index, rank - zero_rank));
+        }
+        start = end;
+    }
+    ranks.sort_by_key(|&(index, _)| index);
+    ranks
+}
+
+/// Calculates the spearman rank correlation coefficient of two vectors
+/// of dimension `dim`, given their non-zero components.
+#[cfg(feature = "std")]
+fn spearman<T, I, J>(lhs: I, rhs: J, dim: usize) -> T
+where
+    T: Real,
+    I: IntoIterator<Item = (usize, T)>,
+    J: IntoIterator<Item = (usize, T)>,
+{
+    let zero = T::zero();
+    let lhs_ranks = shifted_ranks(lhs, dim);
+    let rhs_ranks = shifted_ranks(rhs, dim);
+    let lhs_iter = sparse::heap::Iter::new(&lhs_ranks[..]);
+    let rhs_iter = sparse::heap::Iter::new(&rhs_ranks[..]);
+    let pairs = lhs_iter.outer_join(rhs_iter).map(|(_, (lhs, rhs))| {
+        (lhs.unwrap_or(zero), rhs.unwrap_or(zero))
+    });
+    pearson(dim, pairs)
+}
+
+/// The trait for vector types supporting reductions and summary statistics
+///
+/// Sparse vectors of known dimension take their implicit zero components
+/// into account, while sparse vectors of unknown dimension only consider
+/// their stored components.
+///
+/// NaN components propagate into the results, with `min`, `max`, `argmin`
+/// and `argmax` returning the first NaN component. The `nan_*` variants
+/// ignore NaN components instead.
+pub trait Statistics {
+    /// The type of the vector's scalar components
+    type Scalar;
+
+    /// Counts the non-zero components of `self`.
+    fn nnz(&self) -> usize;
+
+    /// Calculates the sum of `self`'s components.
+    fn sum(&self) -> Self::Scalar;
+
+    /// Calculates the sum of `self`'s components, ignoring NaN components.
+    fn nan_sum(&self) -> Self::Scalar
+    where
+        Self::Scalar: Float;
+
+    /// Calculates the arithmetic mean of `self`'s components.
+    ///
+    /// Returns `None` if `self` has no components.
+    fn mean(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float;
+
+    /// Calculates the arithmetic mean of `self`'s components, ignoring NaN components.
+    ///
+    /// Returns `None` if `self` has no components other than NaN.
+    fn nan_mean(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float;
+
+    /// Calculates the (population) variance of `self`'s components.
+    ///
+    /// Returns `None` if `self` has no components.
+    fn variance(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float;
+
+    /// Calculates the (population) variance of `self`'s components, ignoring NaN components.
+    ///
+    /// Returns `None` if `self` has no components other than NaN.
+    fn nan_variance(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float;
+
+    /// Returns the smallest of `self`'s components.
+    fn min(&self) -> Option<Self::Scalar>;
+
+    /// Returns the smallest of `self`'s components, ignoring NaN components.
+    fn nan_min(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float;
+
+    /// Returns the largest of `self`'s components.
+    fn max(&self) -> Option<Self::Scalar>;
+
+    /// Returns the largest of `self`'s components, ignoring NaN components.
+    fn nan_max(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float;
+
+    /// Returns the index of the first smallest of `self`'s components.
+    fn argmin(&self) -> Option<usize>;
+
+    /// Returns the index of the first smallest of `self`'s components,
+    /// ignoring NaN components.
+    fn nan_argmin(&self) -> Option<usize>
+    where
+        Self::Scalar: Float;
+
+    /// Returns the index of the first largest of `self`'s components.
+    fn argmax(&self) -> Option<usize>;
+
+    /// Returns the index of the first largest of `self`'s components,
+    /// ignoring NaN components.
+    fn nan_argmax(&self) -> Option<usize>
+    where
+        Self::Scalar: Float;
+}
+
+/// Returns whether `value` is incomparable to itself (i.e. NaN).
+#[inline]
+fn is_nan<T>(value: &T) -> bool
+where
+    T: PartialOrd,
+{
+    value.partial_cmp(value).is_none()
+}
+
+/// Calculates the sum of `components`, optionally skipping NaN components.
+fn sum<T, I>(components: I, skip_nan: bool) -> T
+where
+    T: Copy + PartialOrd + Zero,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    components.into_iter().fold(T::zero(), |sum, (_, value)| {
+        if skip_nan && is_nan(&value) { sum } else { sum + value }
+    })
+}
+
+/// Calculates the mean and (population) variance of `components`
+/// together with `zeros` further zero components, optionally skipping NaN components.
+fn moments<T, I>(components: I, zeros: usize, skip_nan: bool) -> Option<(T, T)>
+where
+    T: Float,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    let cast = |value: usize| -> T { NumCast::from(value).expect("count should be representable") };
+    let zero = T::zero();
+    let (mut count, mut mean, mut m2) = (0, zero, zero);
+    for (_, value) in components {
+        if skip_nan && value.is_nan() {
+            continue;
+        }
+        count += 1;
+        let delta = value - mean;
+        mean = mean + (delta / cast(count));
+        m2 = m2 + (delta * (value - mean));
+    }
+    let total = count + zeros;
+    if total == 0 {
+        return None;
+    }
+    let (count, zeros, total) = (cast(count), cast(zeros), cast(total));
+    let delta = zero - mean;
+    let mean = mean + (delta * zeros / total);
+    let m2 = m2 + (delta * delta * count * zeros / total);
+    Some((mean, m2 / total))
+}
+
+/// Finds the first of `components` which no other component is preferred over,
+/// also considering an implicit zero component at index `zero` (if given).
+///
+/// Returns the first NaN component, unless skipping NaN components.
+fn extremum<T, I, F>(components: I, zero: Option<usize>, skip_nan: bool, prefer: F) -> Option<(usize, T)>
+where
+    T: Copy + PartialOrd + Zero,
+    I: IntoIterator<Item = (usize, T)>,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut best: Option<(usize, T)> = None;
+    for (index, value) in components {
+        if is_nan(&value) {
+            if skip_nan {
+                continue;
+            }
+            return Some((index, value));
+        }
+        match best {
+            Some((_, current)) if !prefer(&value, &current) => {},
+            _ => best = Some((index, value)),
+        }
+    }
+    match (best, zero) {
+        (Some((index, value)), Some(zero_index)) => {
+            let zero = T::zero();
+            if prefer(&zero, &value) || (zero == value && zero_index < index) {
+                Some((zero_index, zero))
+            } else {
+                best
+            }
+        },
+        (None, Some(zero_index)) => Some((zero_index, T::zero())),
+        (best, None) => best,
+    }
+}
+
+/// The trait for vector types supporting the selection of their largest or smallest components
+///
+/// Sparse vectors only consider their stored components.
+/// NaN components are ordered after all other components,
+/// while tied components are ordered by their index.
+#[cfg(feature = "std")]
+pub trait Selection {
+    /// The type of the vector's scalar components
+    type Scalar;
+
+    /// Returns the `k` largest of `self`'s components, in descending order.
+    fn top_k(&self, k: usize) -> Vec<(usize, Self::Scalar)>;
+
+    /// Returns the `k` smallest of `self`'s components, in ascending order.
+    fn bottom_k(&self, k: usize) -> Vec<(usize, Self::Scalar)>;
+
+    /// Returns the `k` components of `self` with the largest magnitude,
+    /// in descending order of magnitude.
+    fn top_k_by_magnitude(&self, k: usize) -> Vec<(usize, Self::Scalar)>
+    where
+        Self::Scalar: Signed;
+
+    /// Returns all of `self`'s components, in ascending order.
+    fn argsort(&self) -> Vec<(usize, Self::Scalar)>;
+
+    /// Returns a sparse vector of `self`'s dimension, containing only
+    /// the `k` non-zero components of `self` with the largest magnitude.
+    fn sparsify_top_k(&self, k: usize) -> sparse::heap::SparseVector<Self::Scalar>
+    where
+        Self::Scalar: Signed;
+}
+
+/// Orders `lhs` before `rhs` if it is smaller, placing NaN after all other values.
+#[cfg(feature = "std")]
+fn ascending<T>(lhs: &T, rhs: &T) -> Ordering
+where
+    T: PartialOrd,
+{
+    lhs.partial_cmp(rhs).unwrap_or_else(|| is_nan(lhs).cmp(&is_nan(rhs)))
+}
+
+/// Orders `lhs` before `rhs` if it is larger, placing NaN after all other values.
+#[cfg(feature = "std")]
+fn descending<T>(lhs: &T, rhs: &T) -> Ordering
+where
+    T: PartialOrd,
+{
+    rhs.partial_cmp(lhs).unwrap_or_else(|| is_nan(lhs).cmp(&is_nan(rhs)))
+}
+
+/// Selects the first `k` of `components` in the order given by `compare`,
+/// breaking ties by index.
+///
+/// Only sorts the selected components, partitioning the rest in linear time.
+#[cfg(feature = "std")]
+fn select<T, I, F>(components: I, k: usize, mut compare: F) -> Vec<(usize, T)>
+where
+    I: IntoIterator<Item = (usize, T)>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut compare = |lhs: &(usize, T), rhs: &(usize, T)| {
+        compare(&lhs.1, &rhs.1).then(lhs.0.cmp(&rhs.0))
+    };
+    let mut components: Vec<_> = components.into_iter().collect();
+    if k == 0 {
+        components.clear();
+    } else if k < components.len() {
+        components.select_nth_unstable_by(k - 1, &mut compare);
+        components.truncate(k);
+    }
+    components.sort_unstable_by(compare);
+    components
+}
+
+/// Creates a sparse vector of dimension `dim` (if known) from the
+/// non-zero ones of the given `k` components of largest magnitude.
+#[cfg(feature = "std")]
+fn sparsify<T, I>(components: I, k: usize, dim: Option<usize>) -> sparse::heap::SparseVector<T>
+where
+    T: Copy + Signed + PartialOrd,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    let nonzero = components.into_iter().filter(|&(_, value)| !value.is_zero());
+    let mut components = select(nonzero, k, |lhs, rhs| descending(&lhs.abs(), &rhs.abs()));
+    components.sort_unstable_by_key(|&(index, _)| index);
+    let vector = sparse::heap::SparseVector::from_sorted_unchecked(components);
+    match dim {
+        Some(dim) => vector.with_dim(dim).expect("indices should lie within dimension"),
+        None => vector,
+    }
+}
+
+/// The trait for types supporting the calculation of norms
+pub trait Norm: Sized {
+    /// The scalar type returned by `self`'s norms
+    type Scalar;
+
+    /// Calculates the L1 (manhattan) norm of `self`.
+    fn l1(&self) -> Self::Scalar;
+
+    /// Calculates the squared L2 (euclidian) norm of `self`.
+    fn squared_l2(&self) -> Self::Scalar;
+
+    /// Calculates the L2 (euclidian) norm of `self`.
+    fn l2(&self) -> Self::Scalar
+    where
+        Self::Scalar: Real,
+    {
+        self.squared_l2().sqrt()
+    }
+
+    /// Calculates the L-infinity (maximum) norm of `self`.
+    fn linf(&self) -> Self::Scalar;
+
+    /// Calculates the Lp norm of `self`.
+    ///
+    /// Only yields a norm for `p >= 1`.
+    fn lp(&self, p: Self::Scalar) -> Self::Scalar
+    where
+        Self::Scalar: Real;
+
+    /// Scales `self` to unit L2 norm.
+    ///
+    /// Fails if `self` is a zero vector.
+    fn normalize(&mut self) -> Result<(), Error>
+    where
+        Self: DivAssign<<Self as Norm>::Scalar>,
+        Self::Scalar: Real,
+    {
+        let norm = self.l2();
+        if norm.is_zero() {
+            return Err(Error::ZeroVector);
+        }
+        *self /= norm;
+        Ok(())
+    }
+
+    /// Returns `self` scaled to unit L2 norm.
+    ///
+    /// Fails if `self` is a zero vector.
+    fn normalized(mut self) -> Result<Self, Error>
+    where
+        Self: DivAssign<<Self as Norm>::Scalar>,
+        Self::Scalar: Real,
+    {
+        self.normalize()?;
+        Ok(self)
+    }
+}
+
+/// The trait for vector types supporting operations which check
+/// their operands' dimensions, even in release builds.
+pub trait CheckedVectorOps: Sized {
+    /// Checks whether `self` and `rhs` belong to the same vector space.
+    fn check_dim(&self, rhs: &Self) -> Result<(), DimensionMismatch>;
+
+    /// Calculates `self + rhs`, if `self` and `rhs` have matching dimensions.
+    fn checked_add<'a>(self, rhs: &'a Self) -> Result<Self, DimensionMismatch>
+    where
+        Self: Add<&'a Self, Output = Self>,
+    {
+        self.check_dim(rhs)?;
+        Ok(self + rhs)
+    }
+
+    /// Calculates `self - rhs`, if `self` and `rhs` have matching dimensions.
+    fn checked_sub<'a>(self, rhs: &'a Self) -> Result<Self, DimensionMismatch>
+    where
+        Self: Sub<&'a Self, Output = Self>,
+    {
+        self.check_dim(rhs)?;
+        Ok(self - rhs)
+    }
+
+    /// Calculates `(self * a) + b`, if `self` and `b` have matching dimensions.
+    fn checked_mul_add<'a, T>(self, a: T, b: &'a Self) -> Result<Self, DimensionMismatch>
+    where
+        Self: MulAdd<T, &'a Self, Output = Self>,
+    {
+        self.check_dim(b)?;
+        Ok(self.mul_add(a, b))
+    }
+
+    /// Calculates the dot-product between `self` and `rhs`,
+    /// if `self` and `rhs` have matching dimensions.
+    fn checked_dot(&self, rhs: &Self) -> Result<<Self as Dot>::Scalar, DimensionMismatch>
+    where
+        Self: Dot,
+    {
+        self.check_dim(rhs)?;
+        Ok(self.dot(rhs))
+    }
+
+    /// Calculates the squared euclidian distance between `self` and `rhs`,
+    /// if `self` and `rhs` have matching dimensions.
+    fn checked_squared_distance(&self, rhs: &Self) -> Result<<Self as Distance>::Scalar, DimensionMismatch>
+    where
+        Self: Distance,
+    {
+        self.check_dim(rhs)?;
+        Ok(self.squared_distance(rhs))
+    }
+
+    /// Calculates the euclidian distance between `self` and `rhs`,
+    /// if `self` and `rhs` have matching dimensions.
+    fn checked_distance(&self, rhs: &Self) -> Result<<Self as Distance>::Scalar, DimensionMismatch>
+    where
+        Self: Distance,
+        <Self as Distance>::Scalar: Real,
+    {
+        self.check_dim(rhs)?;
+        Ok(self.distance(rhs))
+    }
+}
@@ -0,0 +1,214 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Macros for creating vectors from their components.
+
+/// Creates a `dense::heap::DenseVector` containing the given components.
+///
+/// ```
+/// # #[macro_use(dense_vec)] extern crate vectors;
+/// # fn main() {
+/// let vector = dense_vec![0.0, 1.0, 2.0];
+/// let zeros = dense_vec![0.0; 3];
+/// # assert_eq!(vector.len(), zeros.len());
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! dense_vec {
+    ($value:expr; $len:expr) => {
+        $crate::dense::heap::DenseVector::from(vec![$value; $len])
+    };
+    ($($value:expr),* $(,)?) => {
+        $crate::dense::heap::DenseVector::from(vec![$($value),*])
+    };
+}
+
+/// Creates a `dense::stack::DenseVector` containing the given components.
+///
+/// ```
+/// # #[macro_use(dense_stack_vec)] extern crate vectors;
+/// # fn main() {
+/// let vector = dense_stack_vec![0.0, 1.0, 2.0];
+/// let zeros = dense_stack_vec![0.0; 3];
+/// # assert_eq!(vector.len(), zeros.len());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dense_stack_vec {
+    ($value:expr; $len:expr) => {
+        $crate::dense::stack::DenseVector::from([$value; $len])
+    };
+    ($($value:expr),* $(,)?) => {
+        $crate::dense::stack::DenseVector::from([$($value),*])
+    };
+}
+
+/// Creates a `sparse::heap::SparseVector` containing the given components,
+/// optionally followed by the vector's dimension.
+///
+/// The components may be given in any order and get sorted by index.
+/// Duplicate indices are rejected at compile time if all indices are literals,
+/// and cause a panic otherwise, as do indices lying outside of the dimension.
+///
+/// ```
+/// # #[macro_use(sparse_vec)] extern crate vectors;
+/// # fn main() {
+/// let vector = sparse_vec![4 => 0.5, 0 => 1.0];
+/// let bounded = sparse_vec![0 => 1.0, 4 => 0.5; 8];
+/// # assert_eq!(bounded.dim(), Some(8));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use(sparse_vec)] extern crate vectors;
+/// # fn main() {
+/// let vector = sparse_vec![0 => 1.0, 0 => 0.5];
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! sparse_vec {
+    (@unchecked [$($item:expr),*]) => {{
+        let mut items = vec![$($item),*];
+        $crate::sparse::sort_items(&mut items[..]);
+        $crate::sparse::heap::SparseVector::from_sorted_unchecked(items)
+    }};
+    (@unchecked [$($item:expr),*]; $dim:expr) => {
+        match $crate::sparse_vec!(@unchecked [$($item),*]).with_dim($dim) {
+            Ok(vector) => vector,
+            Err(error) => panic!("{}", error),
+        }
+    };
+    ($($index:literal => $value:expr),* ; $dim:expr) => {{
+        const _: () = $crate::sparse::assert_unique_indices(&[$($index),*]);
+        $crate::sparse_vec!(@unchecked [$(($index, $value)),*]; $dim)
+    }};
+    ($($index:literal => $value:expr),* $(,)?) => {{
+        const _: () = $crate::sparse::assert_unique_indices(&[$($index),*]);
+        $crate::sparse_vec!(@unchecked [$(($index, $value)),*])
+    }};
+    ($($index:expr => $value:expr),* ; $dim:expr) => {
+        $crate::sparse_vec!(@unchecked [$(($index, $value)),*]; $dim)
+    };
+    ($($index:expr => $value:expr),* $(,)?) => {
+        $crate::sparse_vec!(@unchecked [$(($index, $value)),*])
+    };
+}
+
+/// Creates a `sparse::stack::SparseVector` containing the given components,
+/// optionally followed by the vector's dimension.
+///
+/// The vector's capacity matches the number of components.
+/// Indices are handled like with `sparse_vec!`.
+///
+/// ```
+/// # #[macro_use(sparse_stack_vec)] extern crate vectors;
+/// # fn main() {
+/// let vector = sparse_stack_vec![4 => 0.5, 0 => 1.0];
+/// let bounded = sparse_stack_vec![0 => 1.0, 4 => 0.5; 8];
+/// # assert_eq!(bounded.dim(), Some(8));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! sparse_stack_vec {
+    (@unchecked [$($item:expr),*]) => {{
+        let mut items = [$($item),*];
+        $crate::sparse::sort_items(&mut items[..]);
+        $crate::sparse::stack::SparseVector::from_sorted_unchecked(items)
+    }};
+    (@unchecked [$($item:expr),*]; $dim:expr) => {
+        match $crate::sparse_stack_vec!(@unchecked [$($item),*]).with_dim($dim) {
+            Ok(vector) => vector,
+            Err(error) => panic!("{}", error),
+        }
+    };
+    ($($index:literal => $value:expr),* ; $dim:expr) => {{
+        const _: () = $crate::sparse::assert_unique_indices(&[$($index),*]);
+        $crate::sparse_stack_vec!(@unchecked [$(($index, $value)),*]; $dim)
+    }};
+    ($($index:literal => $value:expr),* $(,)?) => {{
+        const _: () = $crate::sparse::assert_unique_indices(&[$($index),*]);
+        $crate::sparse_stack_vec!(@unchecked [$(($index, $value)),*])
+    }};
+    ($($index:expr => $value:expr),* ; $dim:expr) => {
+        $crate::sparse_stack_vec!(@unchecked [$(($index, $value)),*]; $dim)
+    };
+    ($($index:expr => $value:expr),* $(,)?) => {
+        $crate::sparse_stack_vec!(@unchecked [$(($index, $value)),*])
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryFrom;
+
+    use dense;
+    use sparse;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn dense_vec() {
+        expect!(dense_vec![1.0, 2.0, 3.0]).to(be_equal_to(dense::heap::DenseVector::from(vec![1.0, 2.0, 3.0])));
+        expect!(dense_vec![0.5; 2]).to(be_equal_to(dense::heap::DenseVector::from(vec![0.5, 0.5])));
+    }
+
+    #[test]
+    fn dense_stack_vec() {
+        expect!(dense_stack_vec![1.0, 2.0, 3.0]).to(be_equal_to(dense::stack::DenseVector::from([1.0, 2.0, 3.0])));
+        expect!(dense_stack_vec![0.5; 2]).to(be_equal_to(dense::stack::DenseVector::from([0.5, 0.5])));
+    }
+
+    #[test]
+    fn sparse_vec() {
+        let expected = sparse::heap::SparseVector::try_from(vec![(0, 1.0), (2, 3.0), (5, 2.0)]).unwrap();
+        expect!(sparse_vec![5 => 2.0, 0 => 1.0, 2 => 3.0]).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn sparse_vec_dim() {
+        let expected = sparse::heap::SparseVector::try_from(vec![(0, 1.0), (5, 2.0)]).unwrap().with_dim(6).unwrap();
+        expect!(sparse_vec![5 => 2.0, 0 => 1.0; 6]).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn sparse_vec_empty() {
+        let subject: sparse::heap::SparseVector<f64> = sparse_vec![];
+        expect!(subject.is_empty()).to(be_true());
+    }
+
+    #[test]
+    fn sparse_vec_expressions() {
+        let offset = 2;
+        let expected = sparse::heap::SparseVector::try_from(vec![(1, 1.0), (3, 2.0)]).unwrap();
+        expect!(sparse_vec![offset + 1 => 2.0, offset - 1 => 1.0]).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 occurs more than once")]
+    fn sparse_vec_duplicate_expressions() {
+        let index = 1;
+        let _ = sparse_vec![index => 2.0, 1 => 1.0];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn sparse_vec_out_of_bounds() {
+        let _ = sparse_vec![0 => 1.0, 5 => 2.0; 5];
+    }
+
+    #[test]
+    fn sparse_stack_vec() {
+        let expected = sparse::stack::SparseVector::try_from([(0, 1.0), (2, 3.0), (5, 2.0)]).unwrap().with_dim(8).unwrap();
+        expect!(sparse_stack_vec![5 => 2.0, 0 => 1.0, 2 => 3.0; 8]).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 occurs more than once")]
+    fn sparse_stack_vec_duplicate_expressions() {
+        let index = 1;
+        let _ = sparse_stack_vec![index => 2.0, 1 => 1.0];
+    }
+}
@@ -0,0 +1,680 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Distance metrics, for use as type parameters or trait objects.
+//!
+//! The metrics are implemented for any vector type implementing `PairwiseFold`,
+//! i.e. for all of the crate's dense and sparse vector types.
+//! Components missing from either operand are treated as zero.
+
+use num_traits::{Signed, Zero, real::Real};
+use ordered_iter::OrderedMapIterator;
+
+use arrayvec::Array;
+
+use {dense, sparse, CheckedVectorOps, Correlation, DimensionMismatch};
+
+/// The trait for distance metrics between vectors of type `V`
+pub trait Metric<V> {
+    /// The scalar type returned by the metric
+    type Scalar;
+
+    /// Calculates the distance between `lhs` and `rhs`.
+    ///
+    /// Panics if `lhs` and `rhs` have mismatching dimensions.
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar;
+}
+
+/// The euclidian (L2) metric
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+/// The manhattan (L1) metric
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Manhattan;
+
+/// The chebyshev (L-infinity) metric
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Chebyshev;
+
+/// The minkowski (Lp) metric
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Minkowski<T> {
+    p: T,
+}
+
+/// The canberra metric
+///
+/// Components which are zero in both vectors do not contribute to the distance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Canberra;
+
+/// The correlation distance (`1 - pearson`)
+///
+/// Only a semi-metric, as it violates the triangle inequality.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CorrelationDistance;
+
+/// The euclidian metric, weighting each dimension's squared difference
+///
+/// Panics when measuring vectors whose dimension differs from the weights'.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedEuclidean<T> {
+    weights: dense::heap::DenseVector<T>,
+}
+
+/// The manhattan metric, weighting each dimension's absolute difference
+///
+/// Panics when measuring vectors whose dimension differs from the weights'.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedManhattan<T> {
+    weights: dense::heap::DenseVector<T>,
+}
+
+/// The mahalanobis distance for a diagonal covariance matrix,
+/// i.e. for dimensions with independent variances
+///
+/// Panics when measuring vectors whose dimension differs from the variances'.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mahalanobis<T> {
+    inverse_variances: dense::heap::DenseVector<T>,
+}
+
+/// The bray-curtis dissimilarity
+///
+/// Only a metric for vectors with non-negative components.
+/// The dissimilarity between two zero vectors is zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BrayCurtis;
+
+impl<T> Minkowski<T>
+where
+    T: Real,
+{
+    /// Creates a minkowski metric of order `p`.
+    ///
+    /// Panics if `p < 1`, for which the minkowski distance is not a metric.
+    pub fn new(p: T) -> Self {
+        assert!(p >= T::one(), "minkowski metric requires p >= 1");
+        Self { p }
+    }
+
+    /// The metric's order
+    pub fn p(&self) -> T {
+        self.p
+    }
+}
+
+/// Panics unless every one of `weights` is non-negative.
+#[cfg(feature = "std")]
+fn assert_weights<T>(weights: &dense::heap::DenseVector<T>)
+where
+    T: Copy + Zero + PartialOrd,
+{
+    let valid = weights.as_slice().iter().all(|&weight| weight >= T::zero());
+    assert!(valid, "weights must be non-negative");
+}
+
+#[cfg(feature = "std")]
+impl<T> WeightedEuclidean<T>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Creates a weighted euclidian metric from per-dimension `weights`.
+    ///
+    /// Panics if any of `weights` is negative.
+    pub fn new(weights: dense::heap::DenseVector<T>) -> Self {
+        assert_weights(&weights);
+        Self { weights }
+    }
+
+    /// The metric's per-dimension weights
+    pub fn weights(&self) -> &dense::heap::DenseVector<T> {
+        &self.weights
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> WeightedManhattan<T>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Creates a weighted manhattan metric from per-dimension `weights`.
+    ///
+    /// Panics if any of `weights` is negative.
+    pub fn new(weights: dense::heap::DenseVector<T>) -> Self {
+        assert_weights(&weights);
+        Self { weights }
+    }
+
+    /// The metric's per-dimension weights
+    pub fn weights(&self) -> &dense::heap::DenseVector<T> {
+        &self.weights
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Mahalanobis<T>
+where
+    T: Real,
+{
+    /// Creates a diagonal mahalanobis distance from per-dimension `variances`.
+    ///
+    /// Panics if any of `variances` is not positive.
+    pub fn new(variances: dense::heap::DenseVector<T>) -> Self {
+        let inverse_variances = variances.as_slice().iter().map(|&variance| {
+            assert!(variance > T::zero(), "variances must be positive");
+            variance.recip()
+        }).collect();
+        Self { inverse_variances }
+    }
+
+    /// The distance's per-dimension inverse variances
+    pub fn inverse_variances(&self) -> &dense::heap::DenseVector<T> {
+        &self.inverse_variances
+    }
+}
+
+/// The trait for vector types supporting folds over the components
+/// of two vectors, paired up by index
+pub trait PairwiseFold {
+    /// The type of the vector's scalar components
+    type Scalar;
+
+    /// Folds over the indices and components of `self` and `rhs` pairwise,
+    /// substituting zero for components missing from either of them.
+    ///
+    /// Panics if `self` and `rhs` have mismatching dimensions.
+    fn fold_indexed_pairs<B, F>(&self, rhs: &Self, init: B, f: F) -> B
+    where
+        F: FnMut(B, usize, Self::Scalar, Self::Scalar) -> B;
+
+    /// Checks whether `self` belongs to a vector space of dimension `dim`.
+    ///
+    /// Vectors of unknown dimension belong to it if their components lie within `dim`.
+    fn check_within(&self, dim: usize) -> Result<(), DimensionMismatch>;
+
+    /// Folds over the components of `self` and `rhs` pairwise,
+    /// substituting zero for components missing from either of them.
+    ///
+    /// Panics if `self` and `rhs` have mismatching dimensions.
+    fn fold_pairs<B, F>(&self, rhs: &Self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Scalar, Self::Scalar) -> B,
+    {
+        self.fold_indexed_pairs(rhs, init, |acc, _, lhs, rhs| f(acc, lhs, rhs))
+    }
+}
+
+macro_rules! impl_pairwise_fold {
+    ($(#[$attr:meta])* impl<$($param:ident),*> for $vector:ty, dim: |$this:ident| $dim:expr, where $($bound:tt)*) => {
+        $(#[$attr])*
+        impl<T, $($param),*> PairwiseFold for $vector
+        where
+            T: Copy + Zero,
+            $($bound)*
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn fold_indexed_pairs<B, F>(&self, rhs: &Self, init: B, mut f: F) -> B
+            where
+                F: FnMut(B, usize, T, T) -> B,
+            {
+                if let Err(error) = self.check_dim(rhs) {
+                    panic!("{}", error);
+                }
+                let lhs_iter = self.iter();
+                let rhs_iter = rhs.iter();
+                lhs_iter.outer_join(rhs_iter).fold(init, |acc, (index, (lhs, rhs))| {
+                    f(acc, index, lhs.unwrap_or_else(T::zero), rhs.unwrap_or_else(T::zero))
+                })
+            }
+
+            #[inline]
+            fn check_within(&self, dim: usize) -> Result<(), DimensionMismatch> {
+                let $this = self;
+                let (found, last) = $dim;
+                check_within(dim, found, last)
+            }
+        }
+    }
+}
+
+impl_pairwise_fold!(
+    #[cfg(feature = "std")] impl<> for dense::heap::DenseVector<T>,
+    dim: |vector| (Some(vector.len()), None),
+    where
+);
+impl_pairwise_fold!(
+    impl<A> for dense::stack::DenseVector<A>,
+    dim: |vector| (Some(vector.len()), None),
+    where A: Array<Item = T>,
+);
+impl_pairwise_fold!(
+    #[cfg(feature = "std")] impl<> for sparse::heap::SparseVector<T>,
+    dim: |vector| (vector.dim(), vector.iter().last().map(|(index, _)| index)),
+    where
+);
+impl_pairwise_fold!(
+    impl<A> for sparse::stack::SparseVector<A>,
+    dim: |vector| (vector.dim(), vector.iter().last().map(|(index, _)| index)),
+    where A: Array<Item = (usize, T)>,
+);
+
+/// Checks whether a vector of dimension `found` (if known),
+/// whose last component lies at index `last`, belongs to dimension `dim`.
+fn check_within(dim: usize, found: Option<usize>, last: Option<usize>) -> Result<(), DimensionMismatch> {
+    match (found, last) {
+        (Some(found), _) if found != dim => Err(DimensionMismatch { expected: dim, found }),
+        (None, Some(index)) if index >= dim => Err(DimensionMismatch { expected: dim, found: index + 1 }),
+        _ => Ok(()),
+    }
+}
+
+/// Panics unless both `lhs` and `rhs` belong to dimension `dim`.
+#[cfg(feature = "std")]
+fn assert_within<V>(lhs: &V, rhs: &V, dim: usize)
+where
+    V: PairwiseFold,
+{
+    if let Err(error) = lhs.check_within(dim).and_then(|_| rhs.check_within(dim)) {
+        panic!("{}", error);
+    }
+}
+
+impl<V, T> Metric<V> for Euclidean
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        lhs.fold_pairs(rhs, T::zero(), |sum, lhs, rhs| {
+            let delta = lhs - rhs;
+            sum + (delta * delta)
+        }).sqrt()
+    }
+}
+
+impl<V, T> Metric<V> for Manhattan
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Copy + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        lhs.fold_pairs(rhs, T::zero(), |sum, lhs, rhs| {
+            sum + Signed::abs(&(lhs - rhs))
+        })
+    }
+}
+
+impl<V, T> Metric<V> for Chebyshev
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Copy + Signed + PartialOrd,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        lhs.fold_pairs(rhs, T::zero(), |max, lhs, rhs| {
+            let delta = Signed::abs(&(lhs - rhs));
+            if delta > max { delta } else { max }
+        })
+    }
+}
+
+impl<V, T> Metric<V> for Minkowski<T>
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        let p = self.p;
+        lhs.fold_pairs(rhs, T::zero(), |sum, lhs, rhs| {
+            sum + Real::abs(lhs - rhs).powf(p)
+        }).powf(p.recip())
+    }
+}
+
+impl<V, T> Metric<V> for Canberra
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Copy + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        lhs.fold_pairs(rhs, T::zero(), |sum, lhs, rhs| {
+            let denominator = Signed::abs(&lhs) + Signed::abs(&rhs);
+            if denominator.is_zero() {
+                sum
+            } else {
+                sum + (Signed::abs(&(lhs - rhs)) / denominator)
+            }
+        })
+    }
+}
+
+impl<V, T> Metric<V> for BrayCurtis
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Copy + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        let zero = T::zero();
+        let (numerator, denominator) = lhs.fold_pairs(rhs, (zero, zero), |sums, lhs, rhs| {
+            let (numerator, denominator) = sums;
+            (numerator + Signed::abs(&(lhs - rhs)), denominator + Signed::abs(&(lhs + rhs)))
+        });
+        if denominator.is_zero() {
+            zero
+        } else {
+            numerator / denominator
+        }
+    }
+}
+
+impl<V, T> Metric<V> for CorrelationDistance
+where
+    V: Correlation<Scalar = T>,
+    T: Real,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        lhs.correlation_distance(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V, T> Metric<V> for WeightedEuclidean<T>
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        let weights = self.weights.as_slice();
+        assert_within(lhs, rhs, weights.len());
+        lhs.fold_indexed_pairs(rhs, T::zero(), |sum, index, lhs, rhs| {
+            let delta = lhs - rhs;
+            sum + (weights[index] * delta * delta)
+        }).sqrt()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V, T> Metric<V> for WeightedManhattan<T>
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Copy + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        let weights = self.weights.as_slice();
+        assert_within(lhs, rhs, weights.len());
+        lhs.fold_indexed_pairs(rhs, T::zero(), |sum, index, lhs, rhs| {
+            sum + (weights[index] * Signed::abs(&(lhs - rhs)))
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V, T> Metric<V> for Mahalanobis<T>
+where
+    V: PairwiseFold<Scalar = T>,
+    T: Real + Signed,
+{
+    type Scalar = T;
+
+    fn distance(&self, lhs: &V, rhs: &V) -> Self::Scalar {
+        let inverse_variances = self.inverse_variances.as_slice();
+        assert_within(lhs, rhs, inverse_variances.len());
+        lhs.fold_indexed_pairs(rhs, T::zero(), |sum, index, lhs, rhs| {
+            let delta = lhs - rhs;
+            sum + (inverse_variances[index] * delta * delta)
+        }).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use dense::heap::DenseVector;
+    use sparse::{heap, stack};
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn euclidean() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(Euclidean.distance(&lhs, &rhs)).to(be_close_to(5.099));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(Euclidean.distance(&lhs, &rhs)).to(be_close_to(5.099));
+    }
+
+    #[test]
+    fn manhattan() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(Manhattan.distance(&lhs, &rhs)).to(be_close_to(8.0));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(Manhattan.distance(&lhs, &rhs)).to(be_close_to(8.0));
+    }
+
+    #[test]
+    fn manhattan_disjoint() {
+        let lhs = stack::SparseVector::try_from([(0, 1.0), (1, 2.0)]).unwrap();
+        let rhs = stack::SparseVector::try_from([(2, 2.0), (3, 1.0)]).unwrap();
+        expect!(Manhattan.distance(&lhs, &rhs)).to(be_close_to(6.0));
+    }
+
+    #[test]
+    fn chebyshev() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(Chebyshev.distance(&lhs, &rhs)).to(be_close_to(4.0));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(Chebyshev.distance(&lhs, &rhs)).to(be_close_to(4.0));
+    }
+
+    #[test]
+    fn minkowski() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(Minkowski::new(1.0).distance(&lhs, &rhs)).to(be_close_to(8.0));
+        expect!(Minkowski::new(3.0).distance(&lhs, &rhs)).to(be_close_to(4.514));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(Minkowski::new(3.0).distance(&lhs, &rhs)).to(be_close_to(4.514));
+    }
+
+    #[test]
+    fn minkowski_parity() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(Minkowski::new(1.0).distance(&lhs, &rhs)).to(be_close_to(Manhattan.distance(&lhs, &rhs)));
+        expect!(Minkowski::new(2.0).distance(&lhs, &rhs)).to(be_close_to(Euclidean.distance(&lhs, &rhs)));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(Minkowski::new(1.0).distance(&lhs, &rhs)).to(be_close_to(Manhattan.distance(&lhs, &rhs)));
+        expect!(Minkowski::new(2.0).distance(&lhs, &rhs)).to(be_close_to(Euclidean.distance(&lhs, &rhs)));
+    }
+
+    #[test]
+    #[should_panic(expected = "p >= 1")]
+    fn minkowski_invalid_order() {
+        let _ = Minkowski::new(0.5);
+    }
+
+    #[test]
+    fn canberra() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(Canberra.distance(&lhs, &rhs)).to(be_close_to(3.0));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(Canberra.distance(&lhs, &rhs)).to(be_close_to(3.0));
+    }
+
+    #[test]
+    fn canberra_zero_denominator() {
+        let lhs = DenseVector::from(vec![0.0, 1.0, 0.0]);
+        let rhs = DenseVector::from(vec![0.0, -1.0, 2.0]);
+        expect!(Canberra.distance(&lhs, &rhs)).to(be_close_to(2.0));
+        let lhs = heap::SparseVector::try_from(vec![(1, 1.0)]).unwrap().with_dim(3).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, -1.0), (2, 2.0)]).unwrap().with_dim(3).unwrap();
+        expect!(Canberra.distance(&lhs, &rhs)).to(be_close_to(2.0));
+    }
+
+    #[test]
+    fn bray_curtis() {
+        let lhs = DenseVector::from(vec![1.0, 2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, 1.0]);
+        expect!(BrayCurtis.distance(&lhs, &rhs)).to(be_close_to(0.5));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, 1.0)]).unwrap();
+        expect!(BrayCurtis.distance(&lhs, &rhs)).to(be_close_to(0.5));
+    }
+
+    #[test]
+    fn bray_curtis_zero() {
+        let lhs = DenseVector::from(vec![0.0, 0.0]);
+        let rhs = DenseVector::from(vec![0.0, 0.0]);
+        expect!(BrayCurtis.distance(&lhs, &rhs)).to(be_equal_to(0.0));
+        let lhs = heap::SparseVector::<f64>::try_from(vec![]).unwrap();
+        let rhs = heap::SparseVector::<f64>::try_from(vec![]).unwrap();
+        expect!(BrayCurtis.distance(&lhs, &rhs)).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    fn correlation_distance() {
+        let lhs = DenseVector::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let rhs = DenseVector::from(vec![2.0, 4.0, 5.0, 4.0, 5.0]);
+        expect!(CorrelationDistance.distance(&lhs, &rhs)).to(be_close_to(0.2254));
+    }
+
+    #[test]
+    fn weighted_euclidean() {
+        let metric = WeightedEuclidean::new(DenseVector::from(vec![1.0, 0.5, 2.0, 0.25]));
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(metric.distance(&lhs, &rhs)).to(be_close_to(3.082));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(metric.distance(&lhs, &rhs)).to(be_close_to(3.082));
+    }
+
+    #[test]
+    fn weighted_manhattan() {
+        let metric = WeightedManhattan::new(DenseVector::from(vec![1.0, 0.5, 2.0, 0.25]));
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(metric.distance(&lhs, &rhs)).to(be_close_to(3.5));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(metric.distance(&lhs, &rhs)).to(be_close_to(3.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn weighted_manhattan_negative_weight() {
+        let _ = WeightedManhattan::new(DenseVector::from(vec![1.0, -0.5]));
+    }
+
+    #[test]
+    fn mahalanobis() {
+        let metric = Mahalanobis::new(DenseVector::from(vec![1.0, 2.0, 0.5, 4.0]));
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        expect!(metric.distance(&lhs, &rhs)).to(be_close_to(3.082));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0), (1, -2.0), (3, 3.0)]).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0), (3, -1.0)]).unwrap();
+        expect!(metric.distance(&lhs, &rhs)).to(be_close_to(3.082));
+    }
+
+    #[test]
+    #[should_panic(expected = "positive")]
+    fn mahalanobis_zero_variance() {
+        let _ = Mahalanobis::new(DenseVector::from(vec![1.0, 0.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn weighted_euclidean_out_of_bounds() {
+        let metric = WeightedEuclidean::new(DenseVector::from(vec![1.0, 1.0]));
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        let _ = metric.distance(&lhs, &rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn weighted_manhattan_shorter_operands() {
+        let metric = WeightedManhattan::new(DenseVector::from(vec![1.0, 0.5, 2.0, 0.25]));
+        let lhs = DenseVector::from(vec![1.0, -2.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0]);
+        let _ = metric.distance(&lhs, &rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn mahalanobis_sparse_dimension_mismatch() {
+        let metric = Mahalanobis::new(DenseVector::from(vec![1.0, 2.0, 0.5, 4.0]));
+        let lhs = heap::SparseVector::try_from(vec![(0, 1.0)]).unwrap().with_dim(6).unwrap();
+        let rhs = heap::SparseVector::try_from(vec![(1, 1.0)]).unwrap().with_dim(6).unwrap();
+        let _ = metric.distance(&lhs, &rhs);
+    }
+
+    #[test]
+    fn check_within() {
+        let subject = heap::SparseVector::try_from(vec![(1, 1.0), (3, 2.0)]).unwrap();
+        expect!(subject.check_within(4)).to(be_ok());
+        expect!(subject.check_within(3)).to(be_err().value(DimensionMismatch { expected: 3, found: 4 }));
+        let subject = subject.with_dim(5).unwrap();
+        expect!(subject.check_within(4)).to(be_err().value(DimensionMismatch { expected: 4, found: 5 }));
+        let subject = stack::SparseVector::try_from([(1, 1.0)]).unwrap();
+        expect!(subject.check_within(2)).to(be_ok());
+    }
+
+    #[test]
+    fn trait_object() {
+        let lhs = DenseVector::from(vec![1.0, -2.0, 0.0, 3.0]);
+        let rhs = DenseVector::from(vec![0.0, 1.0, 0.0, -1.0]);
+        let metrics: Vec<Box<dyn Metric<DenseVector<f64>, Scalar = f64>>> = vec![
+            Box::new(Manhattan),
+            Box::new(Chebyshev),
+        ];
+        let distances: Vec<f64> = metrics.iter().map(|metric| metric.distance(&lhs, &rhs)).collect();
+        expect!(distances).to(be_equal_to(vec![8.0, 4.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn dimension_mismatch() {
+        let lhs = DenseVector::from(vec![1.0, 2.0]);
+        let rhs = DenseVector::from(vec![1.0, 2.0, 3.0]);
+        let _ = Manhattan.distance(&lhs, &rhs);
+    }
+}
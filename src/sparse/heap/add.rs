@@ -6,35 +6,57 @@ use std::ops::{Add, AddAssign};
 
 use num_traits::Zero;
 use ordered_iter::OrderedMapIterator;
+use arrayvec::Array;
 
+use dense;
+use sparse::{assert_compatible, scatter_dense};
 use super::SparseVector;
 
-impl<T, V> Add<V> for SparseVector<T>
+impl<T> Add<SparseVector<T>> for SparseVector<T>
 where
     T: Copy + Zero + Add<T, Output = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     type Output = Self;
 
     #[inline]
-    fn add(mut self, rhs: V) -> Self::Output {
+    fn add(mut self, rhs: SparseVector<T>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T> Add<&'a SparseVector<T>> for SparseVector<T>
+where
+    T: Copy + Zero + Add<T, Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a SparseVector<T>) -> Self::Output {
         self.add_assign(rhs);
         self
     }
 }
 
-impl<T, V> AddAssign<V> for SparseVector<T>
+impl<T> AddAssign<SparseVector<T>> for SparseVector<T>
 where
     T: Copy + Zero + Add<T, Output = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     #[inline]
-    fn add_assign(&mut self, rhs: V) {
+    fn add_assign(&mut self, rhs: SparseVector<T>) {
+        self.add_assign(&rhs);
+    }
+}
+
+impl<'a, T> AddAssign<&'a SparseVector<T>> for SparseVector<T>
+where
+    T: Copy + Zero + Add<T, Output = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a SparseVector<T>) {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
         self.components = {
-            let iter = rhs.into_iter();
-            let outer_join = self.iter().outer_join(iter);
+            let outer_join = self.iter().outer_join(rhs.iter());
             outer_join.filter_map(|(index, (lhs, rhs))| {
                     let value = match (lhs, rhs) {
                         (Some(l), Some(r)) => l.add(r),
@@ -50,6 +72,103 @@ where
                 })
                 .collect()
         };
+        self.dim = self.dim.or(rhs.dim);
+    }
+}
+
+impl<T> Add<SparseVector<T>> for dense::heap::DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: SparseVector<T>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T> Add<&'a SparseVector<T>> for dense::heap::DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a SparseVector<T>) -> Self::Output {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+impl<T> AddAssign<SparseVector<T>> for dense::heap::DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: SparseVector<T>) {
+        self.add_assign(&rhs);
+    }
+}
+
+impl<'a, T> AddAssign<&'a SparseVector<T>> for dense::heap::DenseVector<T>
+where
+    T: Copy + AddAssign<T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a SparseVector<T>) {
+        scatter_dense(self.as_mut_slice(), rhs.dim, &rhs.components[..], |lhs, rhs| *lhs += rhs);
+    }
+}
+
+impl<T, A> Add<SparseVector<T>> for dense::stack::DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: SparseVector<T>) -> Self::Output {
+        self.add_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A> Add<&'a SparseVector<T>> for dense::stack::DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a SparseVector<T>) -> Self::Output {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+impl<T, A> AddAssign<SparseVector<T>> for dense::stack::DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: SparseVector<T>) {
+        self.add_assign(&rhs);
+    }
+}
+
+impl<'a, T, A> AddAssign<&'a SparseVector<T>> for dense::stack::DenseVector<A>
+where
+    T: Copy + AddAssign<T>,
+    A: Array<Item = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a SparseVector<T>) {
+        scatter_dense(self.as_mut_slice(), rhs.dim, &rhs.components[..], |lhs, rhs| *lhs += rhs);
     }
 }
 
@@ -57,34 +176,60 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn add() {
-        let subject = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]);
-        let expected = SparseVector::from(vec![(0, 0.2), (1, 0.6), (2, 1.2), (3, 0.3), (4, 2.0), (5, 4.4)]);
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.6), (2, 1.2), (3, 0.3), (4, 2.0), (5, 4.4)]).unwrap();
         let result = subject + other;
         expect!(result).to(be_equal_to(expected));
     }
 
     #[test]
     fn add_ref() {
-        let subject = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]);
-        let expected = SparseVector::from(vec![(0, 0.2), (1, 0.6), (2, 1.2), (3, 0.3), (4, 2.0), (5, 4.4)]);
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.6), (2, 1.2), (3, 0.3), (4, 2.0), (5, 4.4)]).unwrap();
         let result = subject + &other;
         expect!(result).to(be_equal_to(expected));
     }
 
     #[test]
     fn add_assign() {
-        let subject = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]);
-        let expected = SparseVector::from(vec![(0, 0.2), (1, 0.6), (2, 1.2), (3, 0.3), (4, 2.0), (5, 4.4)]);
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.6), (2, 1.2), (3, 0.3), (4, 2.0), (5, 4.4)]).unwrap();
 
         let mut result = subject;
         result += &other;
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_out_of_bounds() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5)]).unwrap().with_dim(2).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap();
+        let _ = subject + other;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn add_dimension_mismatch() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5)]).unwrap().with_dim(5).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(3).unwrap();
+        let _ = subject + &other;
+    }
+
+    #[test]
+    fn add_propagates_dim() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(5).unwrap();
+        let result = subject + other;
+        expect!(result.dim()).to(be_some().value(5));
+    }
 }
@@ -0,0 +1,56 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use {CheckedVectorOps, DimensionMismatch};
+use sparse::check_compatible;
+use super::SparseVector;
+
+impl<T> CheckedVectorOps for SparseVector<T> {
+    #[inline]
+    fn check_dim(&self, rhs: &Self) -> Result<(), DimensionMismatch> {
+        check_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn checked_add() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (2, 1.0)]).unwrap().with_dim(3).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(3).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.1), (2, 1.2)]).unwrap().with_dim(3).unwrap();
+        let result = subject.checked_add(&other);
+        expect!(result).to(be_ok().value(expected));
+    }
+
+    #[test]
+    fn checked_add_mismatch() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (2, 1.0)]).unwrap().with_dim(3).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (3, 0.2)]).unwrap();
+        let result = subject.checked_add(&other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 3, found: 4 }));
+    }
+
+    #[test]
+    fn checked_dot_mismatch() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (2, 1.0)]).unwrap().with_dim(3).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(4).unwrap();
+        let result = subject.checked_dot(&other);
+        expect!(result).to(be_err().value(DimensionMismatch { expected: 3, found: 4 }));
+    }
+
+    #[test]
+    fn checked_distance() {
+        let subject = SparseVector::try_from(vec![(0, 3.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 4.0)]).unwrap();
+        let result = subject.checked_distance(&other);
+        expect!(result).to(be_ok().value(5.0));
+    }
+}
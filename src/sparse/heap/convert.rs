@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Zero;
+use arrayvec::Array;
+
+use Error;
+use dense::heap::DenseVector;
+use sparse;
+use super::SparseVector;
+
+impl<T> SparseVector<T>
+where
+    T: Copy + Zero,
+{
+    /// Converts `self` into a dense vector of dimension `dim`.
+    ///
+    /// Fails if `dim` does not match `self.dim()`,
+    /// or if any of `self`'s indices lies outside of `dim`.
+    pub fn to_dense(&self, dim: usize) -> Result<DenseVector<T>, Error> {
+        let components = sparse::to_dense(self.dim, &self.components[..], dim)?;
+        Ok(DenseVector::from(components))
+    }
+}
+
+impl<T, A> From<sparse::stack::SparseVector<A>> for SparseVector<T>
+where
+    A: Array<Item = (usize, T)>,
+{
+    #[inline]
+    fn from(vector: sparse::stack::SparseVector<A>) -> Self {
+        let dim = vector.dim();
+        let components = vector.into_iter().collect();
+        Self { components, dim }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use DimensionMismatch;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn to_dense() {
+        let subject = SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap();
+        let expected = DenseVector::from(vec![0.0, 0.5, 0.0, 0.25, 0.0]);
+        expect!(subject.to_dense(5)).to(be_ok().value(expected));
+    }
+
+    #[test]
+    fn to_dense_out_of_bounds() {
+        let subject = SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap();
+        let error = Error::IndexOutOfBounds { index: 3, dim: 3 };
+        expect!(subject.to_dense(3)).to(be_err().value(error));
+    }
+
+    #[test]
+    fn to_dense_dimension_mismatch() {
+        let subject = SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap().with_dim(4).unwrap();
+        let error = Error::DimensionMismatch(DimensionMismatch { expected: 4, found: 5 });
+        expect!(subject.to_dense(5)).to(be_err().value(error));
+    }
+
+    #[test]
+    fn iter_dense() {
+        let subject = SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap().with_dim(5).unwrap();
+        let expected = vec![(0, 0.0), (1, 0.5), (2, 0.0), (3, 0.25), (4, 0.0)];
+        expect!(subject.iter_dense().collect::<Vec<_>>()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn from_stack() {
+        let subject = sparse::stack::SparseVector::try_from([(1, 0.5), (3, 0.25)]).unwrap().with_dim(4).unwrap();
+        let expected = SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap().with_dim(4).unwrap();
+        expect!(SparseVector::from(subject)).to(be_equal_to(expected));
+    }
+}
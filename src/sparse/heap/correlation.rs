@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::real::Real;
+use ordered_iter::OrderedMapIterator;
+
+use Correlation;
+use sparse::assert_compatible;
+use super::SparseVector;
+
+impl<T> SparseVector<T> {
+    /// The number of components to correlate `self` and `rhs` over
+    fn correlation_len(&self, rhs: &Self) -> usize {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        self.dim.or(rhs.dim).unwrap_or_else(|| {
+            let lhs_len = self.components.last().map_or(0, |&(index, _)| index + 1);
+            let rhs_len = rhs.components.last().map_or(0, |&(index, _)| index + 1);
+            lhs_len.max(rhs_len)
+        })
+    }
+}
+
+impl<T> Correlation for SparseVector<T>
+where
+    T: Real,
+{
+    type Scalar = T;
+
+    fn pearson(&self, rhs: &Self) -> Self::Scalar {
+        let len = self.correlation_len(rhs);
+        let zero = T::zero();
+        let pairs = self.iter().outer_join(rhs.iter()).map(|(_, (lhs, rhs))| {
+            (lhs.unwrap_or(zero), rhs.unwrap_or(zero))
+        });
+        ::pearson(len, pairs)
+    }
+
+    #[cfg(feature = "std")]
+    fn spearman(&self, rhs: &Self) -> Self::Scalar {
+        let len = self.correlation_len(rhs);
+        ::spearman(self.iter(), rhs.iter(), len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn pearson() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (2, 3.0)]).unwrap().with_dim(4).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 4.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.pearson(&other)).to(be_close_to(0.9661));
+    }
+
+    #[test]
+    fn pearson_unknown_dim() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (2, 3.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 4.0)]).unwrap();
+        expect!(subject.pearson(&other)).to(be_close_to(1.0));
+    }
+
+    #[test]
+    fn pearson_large_offset() {
+        let subject = SparseVector::try_from(vec![(0, 1e9 + 1.0), (1, 1e9 + 2.0), (3, 1e9 + 3.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.pearson(&subject)).to(be_close_to(1.0));
+    }
+
+    #[test]
+    fn spearman() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (2, 3.0)]).unwrap().with_dim(4).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 4.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.spearman(&other)).to(be_close_to(0.9487));
+    }
+
+    #[test]
+    fn correlation_distance() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (2, 3.0)]).unwrap().with_dim(4).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 4.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.correlation_distance(&other)).to(be_close_to(0.0339));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn pearson_out_of_bounds() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (2, 3.0)]).unwrap().with_dim(3).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (3, 4.0)]).unwrap();
+        let _ = subject.pearson(&other);
+    }
+}
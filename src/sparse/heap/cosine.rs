@@ -0,0 +1,133 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::real::Real;
+use ordered_iter::OrderedMapIterator;
+use arrayvec::Array;
+
+use Cosine;
+use dense;
+use sparse::{assert_compatible, cosine_similarity_dense};
+use super::SparseVector;
+
+impl<T> Cosine for SparseVector<T>
+where
+    T: Real,
+{
+    type Scalar = T;
+
+    fn cosine_similarity(&self, rhs: &Self) -> Self::Scalar {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let lhs_iter = self.iter();
+        let rhs_iter = rhs.iter();
+        let zero = T::zero();
+        let (dot, lhs_sum, rhs_sum) = lhs_iter.outer_join(rhs_iter).fold((zero, zero, zero), |sums, (_, (lhs, rhs))| {
+            let (dot, lhs_sum, rhs_sum) = sums;
+            let (lhs, rhs) = (lhs.unwrap_or(zero), rhs.unwrap_or(zero));
+            (dot + (lhs * rhs), lhs_sum + (lhs * lhs), rhs_sum + (rhs * rhs))
+        });
+        ::cosine_similarity(dot, lhs_sum, rhs_sum)
+    }
+}
+
+impl<T> Cosine<dense::heap::DenseVector<T>> for SparseVector<T>
+where
+    T: Real,
+{
+    type Scalar = T;
+
+    fn cosine_similarity(&self, rhs: &dense::heap::DenseVector<T>) -> Self::Scalar {
+        cosine_similarity_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+}
+
+impl<T> Cosine<SparseVector<T>> for dense::heap::DenseVector<T>
+where
+    T: Real,
+{
+    type Scalar = T;
+
+    fn cosine_similarity(&self, rhs: &SparseVector<T>) -> Self::Scalar {
+        cosine_similarity_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+}
+
+impl<T, A> Cosine<dense::stack::DenseVector<A>> for SparseVector<T>
+where
+    T: Real,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn cosine_similarity(&self, rhs: &dense::stack::DenseVector<A>) -> Self::Scalar {
+        cosine_similarity_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+}
+
+impl<T, A> Cosine<SparseVector<T>> for dense::stack::DenseVector<A>
+where
+    T: Real,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn cosine_similarity(&self, rhs: &SparseVector<T>) -> Self::Scalar {
+        cosine_similarity_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn cosine_similarity() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, -1.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 1.0)]).unwrap();
+        expect!(subject.cosine_similarity(&other)).to(be_close_to(0.6667));
+    }
+
+    #[test]
+    fn cosine_distance() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, -1.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 1.0)]).unwrap();
+        expect!(subject.cosine_distance(&other)).to(be_close_to(0.3333));
+    }
+
+    #[test]
+    fn cosine_zero() {
+        let subject = SparseVector::<f32>::try_from(vec![]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (1, 1.0), (2, 1.0)]).unwrap();
+        expect!(subject.cosine_similarity(&other)).to(be_equal_to(0.0));
+        expect!(subject.cosine_distance(&other)).to(be_equal_to(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn cosine_similarity_out_of_bounds() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0)]).unwrap().with_dim(2).unwrap();
+        let other = SparseVector::try_from(vec![(0, 2.0), (2, 1.0)]).unwrap();
+        let _ = subject.cosine_similarity(&other);
+    }
+
+    #[test]
+    fn cosine_similarity_heap_dense() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, -1.0)]).unwrap();
+        let other = dense::heap::DenseVector::from(vec![2.0, 1.0, 1.0, 0.0]);
+        expect!(subject.cosine_similarity(&other)).to(be_close_to(0.6667));
+        expect!(other.cosine_similarity(&subject)).to(be_close_to(0.6667));
+    }
+
+    #[test]
+    fn cosine_similarity_stack_dense() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, -1.0)]).unwrap();
+        let other = dense::stack::DenseVector::from([2.0, 1.0, 1.0, 0.0]);
+        expect!(subject.cosine_similarity(&other)).to(be_close_to(0.6667));
+        expect!(other.cosine_similarity(&subject)).to(be_close_to(0.6667));
+    }
+}
@@ -6,8 +6,11 @@ use std::ops::{Add, Mul};
 
 use num_traits::Zero;
 use ordered_iter::OrderedMapIterator;
+use arrayvec::Array;
 
 use Dot;
+use dense;
+use sparse::{assert_compatible, dot_dense};
 use super::SparseVector;
 
 impl<T> Dot for SparseVector<T>
@@ -17,6 +20,7 @@ where
     type Scalar = T;
 
     fn dot(&self, rhs: &Self) -> Self::Scalar {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
         let lhs_iter = self.iter();
         let rhs_iter = rhs.iter();
         lhs_iter.inner_join_map(rhs_iter).fold(T::zero(), |sum, (_, (lhs, rhs))| {
@@ -25,17 +29,97 @@ where
     }
 }
 
+impl<T> Dot<dense::heap::DenseVector<T>> for SparseVector<T>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &dense::heap::DenseVector<T>) -> Self::Scalar {
+        dot_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+}
+
+impl<T> Dot<SparseVector<T>> for dense::heap::DenseVector<T>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &SparseVector<T>) -> Self::Scalar {
+        dot_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+}
+
+impl<T, A> Dot<dense::stack::DenseVector<A>> for SparseVector<T>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &dense::stack::DenseVector<A>) -> Self::Scalar {
+        dot_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+}
+
+impl<T, A> Dot<SparseVector<T>> for dense::stack::DenseVector<A>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+    A: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &SparseVector<T>) -> Self::Scalar {
+        dot_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn dot() {
-        let subject = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]);
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
         let dot = subject.dot(&other);
         expect!(dot).to(be_close_to(1.85));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn dot_dimension_mismatch() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5)]).unwrap().with_dim(2).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(3).unwrap();
+        let _ = subject.dot(&other);
+    }
+
+    #[test]
+    fn dot_dense() {
+        let subject = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::heap::DenseVector::from(vec![0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.dot(&other)).to(be_close_to(1.85));
+        expect!(other.dot(&subject)).to(be_close_to(1.85));
+    }
+
+    #[test]
+    fn dot_stack_dense() {
+        let subject = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::stack::DenseVector::from([0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.dot(&other)).to(be_close_to(1.85));
+        expect!(other.dot(&subject)).to(be_close_to(1.85));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn dot_dense_dimension_mismatch() {
+        let subject = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(4).unwrap();
+        let other = dense::heap::DenseVector::from(vec![0.2, 0.5, 1.0]);
+        let _ = subject.dot(&other);
+    }
 }
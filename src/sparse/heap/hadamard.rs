@@ -0,0 +1,193 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::{Mul, Div};
+
+use num_traits::Zero;
+use ordered_iter::OrderedMapIterator;
+use arrayvec::Array;
+
+use Hadamard;
+use dense;
+use sparse::assert_compatible;
+use super::SparseVector;
+
+impl<T> SparseVector<T>
+where
+    T: Copy,
+{
+    /// Applies `f` to each of `self`'s components and the corresponding component of `dense`.
+    #[inline]
+    fn scale_by_dense<F>(&mut self, dense: &[T], f: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_compatible::<T, T>(self.dim, &self.components[..], Some(dense.len()), &[]);
+        for component in self.components.iter_mut() {
+            component.1 = f(component.1, dense[component.0]);
+        }
+        self.dim = Some(dense.len());
+    }
+
+    /// Divides the components stored in `self` by the corresponding components stored in `rhs`,
+    /// leaving components of `self` missing from `rhs` unchanged.
+    ///
+    /// Unlike `Hadamard::hadamard_div_assign` this never divides by an implicit zero.
+    pub fn hadamard_div_support_assign(&mut self, rhs: &Self)
+    where
+        T: Div<T, Output = T>,
+    {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let components = {
+            let lhs_iter = self.iter();
+            let rhs_iter = rhs.iter();
+            lhs_iter.outer_join(rhs_iter).filter_map(|(index, (lhs, rhs))| {
+                lhs.map(|lhs| (index, rhs.map_or(lhs, |rhs| lhs / rhs)))
+            }).collect()
+        };
+        self.components = components;
+        self.dim = self.dim.or(rhs.dim);
+    }
+
+    /// Divides the components stored in `self` by the corresponding components stored in `rhs`,
+    /// leaving components of `self` missing from `rhs` unchanged.
+    pub fn hadamard_div_support(mut self, rhs: &Self) -> Self
+    where
+        T: Div<T, Output = T>,
+    {
+        self.hadamard_div_support_assign(rhs);
+        self
+    }
+}
+
+impl<T> Hadamard for SparseVector<T>
+where
+    T: Copy + Zero + Mul<T, Output = T> + Div<T, Output = T>,
+{
+    /// Multiplies the components stored in both `self` and `rhs`,
+    /// dropping all other components of `self`.
+    fn hadamard_mul_assign(&mut self, rhs: &Self) {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let components = {
+            let lhs_iter = self.iter();
+            let rhs_iter = rhs.iter();
+            lhs_iter.inner_join_map(rhs_iter).map(|(index, (lhs, rhs))| (index, lhs * rhs)).collect()
+        };
+        self.components = components;
+        self.dim = self.dim.or(rhs.dim);
+    }
+
+    /// Divides the components stored in `self` by the corresponding components of `rhs`,
+    /// dividing by zero where they are missing from `rhs`.
+    ///
+    /// Use `hadamard_div_support_assign` to only divide over the support of `rhs`.
+    fn hadamard_div_assign(&mut self, rhs: &Self) {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let components = {
+            let lhs_iter = self.iter();
+            let rhs_iter = rhs.iter();
+            lhs_iter.outer_join(rhs_iter).filter_map(|(index, (lhs, rhs))| {
+                lhs.map(|lhs| (index, lhs / rhs.unwrap_or_else(T::zero)))
+            }).collect()
+        };
+        self.components = components;
+        self.dim = self.dim.or(rhs.dim);
+    }
+}
+
+impl<T> Hadamard<dense::heap::DenseVector<T>> for SparseVector<T>
+where
+    T: Copy + Mul<T, Output = T> + Div<T, Output = T>,
+{
+    fn hadamard_mul_assign(&mut self, rhs: &dense::heap::DenseVector<T>) {
+        self.scale_by_dense(rhs.as_slice(), |lhs, rhs| lhs * rhs);
+    }
+
+    fn hadamard_div_assign(&mut self, rhs: &dense::heap::DenseVector<T>) {
+        self.scale_by_dense(rhs.as_slice(), |lhs, rhs| lhs / rhs);
+    }
+}
+
+impl<T, A> Hadamard<dense::stack::DenseVector<A>> for SparseVector<T>
+where
+    T: Copy + Mul<T, Output = T> + Div<T, Output = T>,
+    A: Array<Item = T>,
+{
+    fn hadamard_mul_assign(&mut self, rhs: &dense::stack::DenseVector<A>) {
+        self.scale_by_dense(rhs.as_slice(), |lhs, rhs| lhs * rhs);
+    }
+
+    fn hadamard_div_assign(&mut self, rhs: &dense::stack::DenseVector<A>) {
+        self.scale_by_dense(rhs.as_slice(), |lhs, rhs| lhs / rhs);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn hadamard_mul() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.5), (2, 3.0), (3, 2.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(1, 1.0), (3, 8.0)]).unwrap();
+        expect!(subject.hadamard_mul(&other)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn hadamard_div() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.5), (2, 3.0), (3, 2.0)]).unwrap();
+        let result = subject.hadamard_div(&other);
+        expect!(result.len()).to(be_equal_to(3));
+        expect!(result.get(0)).to(be_some().value(&f64::INFINITY));
+        expect!(result.get(1)).to(be_some().value(&4.0));
+        expect!(result.get(3)).to(be_some().value(&2.0));
+    }
+
+    #[test]
+    fn hadamard_div_matches_dense() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.5), (2, 3.0), (3, 2.0)]).unwrap().with_dim(4).unwrap();
+        let dense = dense::heap::DenseVector::from(vec![0.0, 0.5, 3.0, 2.0]);
+        expect!(subject.clone().hadamard_div(&other)).to(be_equal_to(subject.hadamard_div(&dense)));
+    }
+
+    #[test]
+    fn hadamard_div_support() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 4.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.5), (2, 3.0), (3, 2.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 1.0), (1, 4.0), (3, 2.0)]).unwrap();
+        expect!(subject.hadamard_div_support(&other)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn hadamard_mul_heap_dense() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 4.0)]).unwrap();
+        let other = dense::heap::DenseVector::from(vec![2.0, 5.0, 0.5, 4.0]);
+        let expected = SparseVector::try_from(vec![(0, 2.0), (1, 10.0), (3, 16.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.hadamard_mul(&other)).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn hadamard_div_assign_stack_dense() {
+        let mut subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 4.0)]).unwrap();
+        let other = dense::stack::DenseVector::from([2.0, 5.0, 0.5, 4.0]);
+        let expected = SparseVector::try_from(vec![(0, 0.5), (1, 0.4), (3, 1.0)]).unwrap().with_dim(4).unwrap();
+        subject.hadamard_div_assign(&other);
+        expect!(subject).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn hadamard_mul_dense_out_of_bounds() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (4, 4.0)]).unwrap();
+        let other = dense::stack::DenseVector::from([2.0, 5.0, 0.5, 4.0]);
+        let _ = subject.hadamard_mul(&other);
+    }
+}
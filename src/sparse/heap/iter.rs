@@ -2,17 +2,24 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::convert::TryFrom;
 use std::iter::FromIterator;
 
 use super::SparseVector;
 
-pub use sparse::iter::{IntoIter, Iter};
+pub use sparse::iter::{IntoIter, Iter, DenseIter};
 
+/// Collects components into a `SparseVector`.
+///
+/// Panics if the components' indices are not strictly increasing.
 impl<T> FromIterator<(usize, T)> for SparseVector<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
         let items: Vec<_> = iter.into_iter().collect();
-        SparseVector::from(items)
+        match SparseVector::try_from(items) {
+            Ok(vector) => vector,
+            Err(error) => panic!("{}", error),
+        }
     }
 }
 
@@ -54,6 +61,12 @@ mod test {
         expect!(subject.components).to(be_equal_to(values));
     }
 
+    #[test]
+    #[should_panic(expected = "index 1 is out of order")]
+    fn from_iter_unsorted() {
+        let _ = SparseVector::from_iter(vec![(0, 0.1), (2, 0.2), (1, 0.3)]);
+    }
+
     #[test]
     fn into_iter() {
         let values = vec![(0, 0.1), (1, 0.2), (2, 0.3), (4, 0.4), (5, 0.5)];
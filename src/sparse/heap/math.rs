@@ -0,0 +1,60 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Float;
+
+use super::SparseVector;
+
+impl<T> SparseVector<T>
+where
+    T: Float,
+{
+    /// Calculates the absolute value of each of `self`'s stored components.
+    ///
+    /// Unlike the dense `ElementwiseMath` this is limited to functions
+    /// mapping zero to zero, which preserve the vector's sparsity.
+    pub fn abs(self) -> Self {
+        self.map_nonzero(T::abs)
+    }
+
+    /// Calculates the square root of each of `self`'s stored components.
+    pub fn sqrt(self) -> Self {
+        self.map_nonzero(T::sqrt)
+    }
+
+    /// Calculates the sign of each of `self`'s stored components.
+    pub fn signum(self) -> Self {
+        self.map_nonzero(T::signum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn abs() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (2, -2.0)]).unwrap().with_dim(4).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 1.0), (2, 2.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.abs()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn sqrt() {
+        let subject = SparseVector::try_from(vec![(1, 4.0), (3, 9.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(1, 2.0), (3, 3.0)]).unwrap();
+        expect!(subject.sqrt()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn signum() {
+        let subject = SparseVector::try_from(vec![(0, 3.0), (5, -2.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 1.0), (5, -1.0)]).unwrap();
+        expect!(subject.signum()).to(be_equal_to(expected));
+    }
+}
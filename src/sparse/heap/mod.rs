@@ -4,34 +4,79 @@
 
 //! Sparse heap-allocated vector representation.
 
+use std::convert::TryFrom;
 use std::ops::{Add, Sub, Mul, Div};
 use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
 
 use num_traits::{NumAssign, MulAdd, MulAddAssign};
 
-use {Vector, VectorOps, VectorAssignOps};
+use {Error, Vector, VectorOps, VectorAssignOps};
+
+mod access;
 
 mod add;
 mod sub;
 mod mul;
 mod div;
 mod mul_add;
+mod hadamard;
+mod neg;
+mod map;
+mod math;
 
 mod dot;
 mod distance;
+mod norm;
+mod cosine;
+mod correlation;
+mod set;
+mod stats;
+mod select;
+mod checked;
+
+mod convert;
+#[cfg(feature = "serde")]
+mod serde;
 
 mod debug;
 mod iter;
 
-pub use self::iter::{Iter, IntoIter};
+pub use self::iter::{Iter, IntoIter, DenseIter};
 
 /// A sparse heap-allocated multi-dimensional vector.
 #[derive(Clone, PartialEq)]
 pub struct SparseVector<T> {
     components: Vec<(usize, T)>,
+    dim: Option<usize>,
 }
 
 impl<T> SparseVector<T> {
+    /// Creates a `SparseVector` from `items` without validating them.
+    ///
+    /// The caller must ensure that the indices of `items` are strictly increasing,
+    /// as otherwise operations on the vector produce meaningless results.
+    #[inline]
+    pub fn from_sorted_unchecked(items: Vec<(usize, T)>) -> Self {
+        debug_assert!(super::validate(&items[..]).is_ok());
+        Self { components: items, dim: None }
+    }
+
+    /// Sets the dimension of the vector space `self` belongs to.
+    ///
+    /// Fails if any of `self`'s indices lies outside of `dim`.
+    #[inline]
+    pub fn with_dim(mut self, dim: usize) -> Result<Self, Error> {
+        super::validate_dim(&self.components[..], dim)?;
+        self.dim = Some(dim);
+        Ok(self)
+    }
+
+    /// The dimension of the vector space `self` belongs to, if known
+    #[inline]
+    pub fn dim(&self) -> Option<usize> {
+        self.dim
+    }
+
     /// The number of components in `self`
     #[inline]
     pub fn len(&self) -> usize {
@@ -49,12 +94,30 @@ impl<T> SparseVector<T> {
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter::new(&self.components[..])
     }
+
+    /// A borrowing iterator over all of `self`'s components, including zeros
+    ///
+    /// Iterates up to `self.dim()`, or up to the last non-zero component if the
+    /// dimension is unknown.
+    #[inline]
+    pub fn iter_dense<'a>(&'a self) -> DenseIter<'a, T>
+    where
+        T: Copy,
+    {
+        let dim = self.dim.unwrap_or_else(|| {
+            self.components.last().map_or(0, |&(index, _)| index + 1)
+        });
+        DenseIter::new(&self.components[..], dim)
+    }
 }
 
-impl<T> From<Vec<(usize, T)>> for SparseVector<T> {
+impl<T> TryFrom<Vec<(usize, T)>> for SparseVector<T> {
+    type Error = Error;
+
     #[inline]
-    fn from(items: Vec<(usize, T)>) -> Self {
-        Self { components: items }
+    fn try_from(items: Vec<(usize, T)>) -> Result<Self, Self::Error> {
+        super::validate(&items[..])?;
+        Ok(Self { components: items, dim: None })
     }
 }
 
@@ -85,9 +148,45 @@ mod test {
     use expectest::prelude::*;
 
     #[test]
-    fn from() {
-        let values: Vec<_> = vec![(0, 5.0)];
-        let subject = SparseVector::from(values.clone());
+    fn try_from() {
+        let values: Vec<_> = vec![(0, 5.0), (3, 1.0)];
+        let subject = SparseVector::try_from(values.clone()).unwrap();
+        expect!(subject.dim()).to(be_none());
+        expect!(subject.components).to(be_equal_to(values));
+    }
+
+    #[test]
+    fn try_from_unsorted() {
+        let values: Vec<_> = vec![(3, 1.0), (0, 5.0)];
+        let subject = SparseVector::try_from(values);
+        expect!(subject).to(be_err().value(Error::UnsortedIndex { index: 0 }));
+    }
+
+    #[test]
+    fn try_from_duplicate() {
+        let values: Vec<_> = vec![(0, 5.0), (0, 1.0)];
+        let subject = SparseVector::try_from(values);
+        expect!(subject).to(be_err().value(Error::DuplicateIndex { index: 0 }));
+    }
+
+    #[test]
+    fn with_dim() {
+        let values: Vec<_> = vec![(0, 5.0), (3, 1.0)];
+        let subject = SparseVector::try_from(values).unwrap().with_dim(4).unwrap();
+        expect!(subject.dim()).to(be_some().value(4));
+    }
+
+    #[test]
+    fn with_dim_out_of_bounds() {
+        let values: Vec<_> = vec![(0, 5.0), (3, 1.0)];
+        let subject = SparseVector::try_from(values).unwrap().with_dim(3);
+        expect!(subject).to(be_err().value(Error::IndexOutOfBounds { index: 3, dim: 3 }));
+    }
+
+    #[test]
+    fn from_sorted_unchecked() {
+        let values: Vec<_> = vec![(0, 5.0), (3, 1.0)];
+        let subject = SparseVector::from_sorted_unchecked(values.clone());
         expect!(subject.components).to(be_equal_to(values));
     }
 }
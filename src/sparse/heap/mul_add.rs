@@ -7,34 +7,54 @@ use num_traits::{MulAdd, MulAddAssign};
 use num_traits::Zero;
 use ordered_iter::OrderedMapIterator;
 
+use sparse::assert_compatible;
 use super::SparseVector;
 
-impl<T, V> MulAdd<T, V> for SparseVector<T>
+impl<T> MulAdd<T, SparseVector<T>> for SparseVector<T>
 where
     T: Copy + Zero + MulAdd<T, T, Output = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     type Output = Self;
 
     #[inline]
-    fn mul_add(mut self, a: T, b: V) -> Self::Output {
+    fn mul_add(mut self, a: T, b: SparseVector<T>) -> Self::Output {
+        self.mul_add_assign(a, &b);
+        self
+    }
+}
+
+impl<'a, T> MulAdd<T, &'a SparseVector<T>> for SparseVector<T>
+where
+    T: Copy + Zero + MulAdd<T, T, Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(mut self, a: T, b: &'a SparseVector<T>) -> Self::Output {
         self.mul_add_assign(a, b);
         self
     }
 }
 
-impl<T, V> MulAddAssign<T, V> for SparseVector<T>
+impl<T> MulAddAssign<T, SparseVector<T>> for SparseVector<T>
 where
     T: Copy + Zero + MulAdd<T, T, Output = T>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     #[inline]
-    fn mul_add_assign(&mut self, a: T, b: V) {
+    fn mul_add_assign(&mut self, a: T, b: SparseVector<T>) {
+        self.mul_add_assign(a, &b);
+    }
+}
+
+impl<'a, T> MulAddAssign<T, &'a SparseVector<T>> for SparseVector<T>
+where
+    T: Copy + Zero + MulAdd<T, T, Output = T>,
+{
+    #[inline]
+    fn mul_add_assign(&mut self, a: T, b: &'a SparseVector<T>) {
+        assert_compatible(self.dim, &self.components[..], b.dim, &b.components[..]);
         self.components = {
-            let iter = b.into_iter();
-            let outer_join = self.iter().outer_join(iter);
+            let outer_join = self.iter().outer_join(b.iter());
             outer_join.filter_map(|(index, (lhs, rhs))| {
                     let value = match (lhs, rhs) {
                         (Some(l), Some(r)) => l.mul_add(a, r),
@@ -50,6 +70,7 @@ where
                 })
                 .collect()
         };
+        self.dim = self.dim.or(b.dim);
     }
 }
 
@@ -57,34 +78,52 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn mul_add() {
-        let subject = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]);
-        let other = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let expected = SparseVector::from(vec![(0, 0.2), (1, 0.7), (2, 1.4), (3, 0.6), (4, 2.0), (5, 4.8)]);
+        let subject = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.7), (2, 1.4), (3, 0.6), (4, 2.0), (5, 4.8)]).unwrap();
         let result = subject.mul_add(2.0, other);
         expect!(result).to(be_equal_to(expected));
     }
 
     #[test]
     fn mul_add_ref() {
-        let subject = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]);
-        let other = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let expected = SparseVector::from(vec![(0, 0.2), (1, 0.7), (2, 1.4), (3, 0.6), (4, 2.0), (5, 4.8)]);
+        let subject = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.7), (2, 1.4), (3, 0.6), (4, 2.0), (5, 4.8)]).unwrap();
         let result = subject.mul_add(2.0, &other);
         expect!(result).to(be_equal_to(expected));
     }
 
     #[test]
     fn mul_add_assign() {
-        let subject = SparseVector::from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]);
-        let other = SparseVector::from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let expected = SparseVector::from(vec![(0, 0.2), (1, 0.7), (2, 1.4), (3, 0.6), (4, 2.0), (5, 4.8)]);
+        let subject = SparseVector::try_from(vec![(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4)]).unwrap();
+        let other = SparseVector::try_from(vec![(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let expected = SparseVector::try_from(vec![(0, 0.2), (1, 0.7), (2, 1.4), (3, 0.6), (4, 2.0), (5, 4.8)]).unwrap();
 
         let mut result = subject;
         result.mul_add_assign(2.0, &other);
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn mul_add_dimension_mismatch() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5)]).unwrap().with_dim(5).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(3).unwrap();
+        let _ = subject.mul_add(2.0, &other);
+    }
+
+    #[test]
+    fn mul_add_propagates_dim() {
+        let subject = SparseVector::try_from(vec![(0, 0.2), (1, 0.5)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 0.1), (2, 0.2)]).unwrap().with_dim(5).unwrap();
+        let result = subject.mul_add(2.0, other);
+        expect!(result.dim()).to(be_some().value(5));
+    }
 }
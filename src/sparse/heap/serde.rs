@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer, de};
+
+use sparse::{Repr, validate_repr};
+use super::SparseVector;
+
+impl<T> Serialize for SparseVector<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let repr = Repr { dim: self.dim, components: &self.components[..] };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for SparseVector<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Repr { dim, components } = Repr::<Vec<_>>::deserialize(deserializer)?;
+        validate_repr(&components[..], dim).map_err(de::Error::custom)?;
+        Ok(SparseVector { components, dim })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use serde_json;
+    use bincode;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn json() {
+        let subject = SparseVector::try_from(vec![(0, 0.5), (3, 2.0)]).unwrap().with_dim(5).unwrap();
+        let json = serde_json::to_string(&subject).unwrap();
+        expect!(json.as_str()).to(be_equal_to(r#"{"dim":5,"components":[[0,0.5],[3,2.0]]}"#));
+        let result: SparseVector<f64> = serde_json::from_str(&json).unwrap();
+        expect!(result).to(be_equal_to(subject));
+    }
+
+    #[test]
+    fn json_unknown_dim() {
+        let subject = SparseVector::try_from(vec![(0, 0.5), (3, 2.0)]).unwrap();
+        let json = serde_json::to_string(&subject).unwrap();
+        let result: SparseVector<f64> = serde_json::from_str(&json).unwrap();
+        expect!(result).to(be_equal_to(subject));
+    }
+
+    #[test]
+    fn bincode() {
+        let subject = SparseVector::try_from(vec![(0, 0.5), (3, 2.0)]).unwrap().with_dim(5).unwrap();
+        let bytes = bincode::serialize(&subject).unwrap();
+        let result: SparseVector<f64> = bincode::deserialize(&bytes).unwrap();
+        expect!(result).to(be_equal_to(subject));
+    }
+
+    #[test]
+    fn json_unsorted() {
+        let json = r#"{"dim":null,"components":[[3,2.0],[0,0.5]]}"#;
+        let result: Result<SparseVector<f64>, _> = serde_json::from_str(json);
+        expect!(result.unwrap_err().to_string().contains("out of order")).to(be_true());
+    }
+
+    #[test]
+    fn json_duplicate() {
+        let json = r#"{"dim":null,"components":[[3,2.0],[3,0.5]]}"#;
+        let result: Result<SparseVector<f64>, _> = serde_json::from_str(json);
+        expect!(result.unwrap_err().to_string().contains("more than once")).to(be_true());
+    }
+
+    #[test]
+    fn json_out_of_bounds() {
+        let json = r#"{"dim":3,"components":[[0,0.5],[3,2.0]]}"#;
+        let result: Result<SparseVector<f64>, _> = serde_json::from_str(json);
+        expect!(result.unwrap_err().to_string().contains("out of bounds")).to(be_true());
+    }
+}
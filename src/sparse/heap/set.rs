@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Num;
+use ordered_iter::OrderedMapIterator;
+
+use SetSimilarity;
+use sparse::assert_compatible;
+use super::SparseVector;
+
+impl<T> SetSimilarity for SparseVector<T>
+where
+    T: Copy + PartialOrd + Num,
+{
+    type Scalar = T;
+
+    fn support_len(&self) -> usize {
+        self.iter().filter(|&(_, value)| !value.is_zero()).count()
+    }
+
+    fn intersection_len(&self, rhs: &Self) -> usize {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let lhs_iter = self.iter();
+        let rhs_iter = rhs.iter();
+        lhs_iter.inner_join_map(rhs_iter).filter(|&(_, (lhs, rhs))| {
+            !lhs.is_zero() && !rhs.is_zero()
+        }).count()
+    }
+
+    fn weighted_jaccard(&self, rhs: &Self) -> Self::Scalar {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let lhs_iter = self.iter();
+        let rhs_iter = rhs.iter();
+        let zero = T::zero();
+        let (min, max) = lhs_iter.outer_join(rhs_iter).fold((zero, zero), |sums, (_, (lhs, rhs))| {
+            let (min, max) = sums;
+            let (lhs, rhs) = (lhs.unwrap_or(zero), rhs.unwrap_or(zero));
+            if lhs < rhs {
+                (min + lhs, max + rhs)
+            } else {
+                (min + rhs, max + lhs)
+            }
+        });
+        if max.is_zero() {
+            return T::one();
+        }
+        min / max
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn jaccard() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 1.0), (5, 0.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 1.0), (2, 1.0), (3, 3.0), (5, 1.0)]).unwrap();
+        expect!(subject.jaccard(&other)).to(be_close_to(0.4));
+    }
+
+    #[test]
+    fn weighted_jaccard() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 1.0), (5, 0.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 1.0), (2, 1.0), (3, 3.0), (5, 1.0)]).unwrap();
+        expect!(subject.weighted_jaccard(&other)).to(be_close_to(0.25));
+    }
+
+    #[test]
+    fn dice() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 1.0), (5, 0.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 1.0), (2, 1.0), (3, 3.0), (5, 1.0)]).unwrap();
+        expect!(subject.dice(&other)).to(be_close_to(0.5714));
+    }
+
+    #[test]
+    fn overlap() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 1.0), (5, 0.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 1.0), (2, 1.0), (3, 3.0), (5, 1.0)]).unwrap();
+        expect!(subject.overlap(&other)).to(be_close_to(0.6667));
+    }
+
+    #[test]
+    fn hamming() {
+        let subject = SparseVector::try_from(vec![(0, 1.0), (1, 2.0), (3, 1.0), (5, 0.0)]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 1.0), (2, 1.0), (3, 3.0), (5, 1.0)]).unwrap();
+        expect!(subject.hamming(&other)).to(be_equal_to(3));
+    }
+
+    #[test]
+    fn empty() {
+        let subject = SparseVector::<f32>::try_from(vec![]).unwrap();
+        let other = SparseVector::<f32>::try_from(vec![]).unwrap();
+        expect!(subject.jaccard(&other)).to(be_equal_to(1.0));
+        expect!(subject.weighted_jaccard(&other)).to(be_equal_to(1.0));
+        expect!(subject.dice(&other)).to(be_equal_to(1.0));
+        expect!(subject.overlap(&other)).to(be_equal_to(1.0));
+        expect!(subject.hamming(&other)).to(be_equal_to(0));
+    }
+
+    #[test]
+    fn empty_and_non_empty() {
+        let subject = SparseVector::<f32>::try_from(vec![]).unwrap();
+        let other = SparseVector::try_from(vec![(1, 1.0), (2, 1.0), (3, 3.0)]).unwrap();
+        expect!(subject.jaccard(&other)).to(be_equal_to(0.0));
+        expect!(subject.dice(&other)).to(be_equal_to(0.0));
+        expect!(subject.overlap(&other)).to(be_equal_to(0.0));
+        expect!(subject.hamming(&other)).to(be_equal_to(3));
+    }
+}
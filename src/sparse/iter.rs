@@ -2,6 +2,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::iter::Peekable;
+
+use num_traits::Zero;
 use ordered_iter::OrderedMapIterator;
 
 /// `SparseVector`'s `IntoIter`
@@ -70,7 +73,7 @@ impl<'a, T> Iter<'a, T> {
     /// Creates an `Iter` from a slice of dense components
     #[inline]
     pub fn new(iter: &'a [(usize, T)]) -> Self {
-        Iter { inner: iter.into_iter() }
+        Iter { inner: iter.iter() }
     }
 }
 
@@ -82,7 +85,7 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|pair| *pair)
+        self.inner.next().copied()
     }
 
     #[inline]
@@ -109,6 +112,71 @@ where
     type Val = T;
 }
 
+/// `SparseVector`'s zero-filling `Iter`
+pub struct DenseIter<'a, T>
+where
+    T: 'a + Copy
+{
+    index: usize,
+    dim: usize,
+    inner: Peekable<Iter<'a, T>>,
+}
+
+impl<'a, T> DenseIter<'a, T>
+where
+    T: Copy
+{
+    /// Creates a `DenseIter` from a slice of sparse components and their vector's dimension
+    #[inline]
+    pub fn new(iter: &'a [(usize, T)], dim: usize) -> Self {
+        DenseIter { index: 0, dim, inner: Iter::new(iter).peekable() }
+    }
+}
+
+impl<'a, T> Iterator for DenseIter<'a, T>
+where
+    T: Copy + Zero
+{
+    type Item = (usize, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.dim {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        match self.inner.peek() {
+            Some(&(next_index, _)) if next_index == index => self.inner.next(),
+            _ => Some((index, T::zero())),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.dim - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for DenseIter<'a, T>
+where
+    T: Copy + Zero
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.dim - self.index
+    }
+}
+
+impl<'a, T> OrderedMapIterator for DenseIter<'a, T>
+where
+    T: Copy + Zero
+{
+    type Key = usize;
+    type Val = T;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -135,4 +203,15 @@ mod test {
         let expected = values;
         expect!(subject).to(be_equal_to(expected));
     }
+
+    #[test]
+    fn dense_iter() {
+        let values = [(1, 0.1), (2, 0.2), (4, 0.4)];
+        let subject: Vec<_> = {
+            let iter = DenseIter::new(&values[..], 6);
+            iter.collect()
+        };
+        let expected = vec![(0, 0.0), (1, 0.1), (2, 0.2), (3, 0.0), (4, 0.4), (5, 0.0)];
+        expect!(subject).to(be_equal_to(expected));
+    }
 }
@@ -4,8 +4,375 @@
 
 //! Sparse vector representations.
 
+use std::ops::{Add, Mul};
+
+use num_traits::{Signed, Zero, real::Real};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use {bounded, Error, DimensionMismatch};
+
 mod iter;
 
 pub mod stack;
 #[cfg(feature = "std")]
 pub mod heap;
+
+/// Scalars with a statically allocated zero.
+///
+/// Indexing a sparse vector returns a reference to this zero
+/// for components that are not stored in the vector.
+pub trait StaticZero: 'static {
+    /// A reference to a statically allocated zero
+    fn static_zero() -> &'static Self;
+}
+
+macro_rules! impl_static_zero {
+    ($($scalar:ty),*) => {
+        $(
+            impl StaticZero for $scalar {
+                #[inline]
+                fn static_zero() -> &'static Self {
+                    &(0 as $scalar)
+                }
+            }
+        )*
+    }
+}
+
+impl_static_zero!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Checks that the indices of `components` are strictly increasing.
+fn validate<T>(components: &[(usize, T)]) -> Result<(), Error> {
+    for window in components.windows(2) {
+        let (prev, index) = (window[0].0, window[1].0);
+        if index == prev {
+            return Err(Error::DuplicateIndex { index });
+        } else if index < prev {
+            return Err(Error::UnsortedIndex { index });
+        }
+    }
+    Ok(())
+}
+
+/// The serialized representation of a sparse vector.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct Repr<C> {
+    dim: Option<usize>,
+    components: C,
+}
+
+/// Checks that the indices of deserialized `components` are strictly increasing
+/// and lie within `dim` (if given).
+#[cfg(feature = "serde")]
+fn validate_repr<T>(components: &[(usize, T)], dim: Option<usize>) -> Result<(), Error> {
+    validate(components)?;
+    match dim {
+        Some(dim) => validate_dim(components, dim),
+        None => Ok(()),
+    }
+}
+
+/// Sorts `items` by index, panicking if any index occurs more than once.
+///
+/// Used by the `sparse_vec!` and `sparse_stack_vec!` macros.
+#[doc(hidden)]
+pub fn sort_items<T>(items: &mut [(usize, T)]) {
+    items.sort_unstable_by_key(|&(index, _)| index);
+    if let Err(error) = validate(items) {
+        panic!("{}", error);
+    }
+}
+
+/// Panics if any index occurs more than once in `indices`.
+///
+/// Used by the `sparse_vec!` and `sparse_stack_vec!` macros
+/// to reject duplicate literal indices at compile time.
+#[doc(hidden)]
+pub const fn assert_unique_indices(indices: &[usize]) {
+    let mut i = 0;
+    while i < indices.len() {
+        let mut j = i + 1;
+        while j < indices.len() {
+            if indices[i] == indices[j] {
+                panic!("duplicate index in sparse vector literal");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Checks that the indices of `components` lie within `dim`.
+///
+/// Expects the indices of `components` to be strictly increasing.
+fn validate_dim<T>(components: &[(usize, T)], dim: usize) -> Result<(), Error> {
+    match components.last() {
+        Some(&(index, _)) if index >= dim => Err(Error::IndexOutOfBounds { index, dim }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks whether the two vectors given by their dimensions and components
+/// can belong to the same vector space.
+fn check_compatible<T, U>(
+    lhs_dim: Option<usize>,
+    lhs: &[(usize, T)],
+    rhs_dim: Option<usize>,
+    rhs: &[(usize, U)],
+) -> Result<(), DimensionMismatch> {
+    match (lhs_dim, rhs_dim) {
+        (Some(expected), Some(found)) if expected != found => {
+            Err(DimensionMismatch { expected, found })
+        },
+        (Some(expected), None) => match rhs.last() {
+            Some(&(index, _)) if index >= expected => {
+                Err(DimensionMismatch { expected, found: index + 1 })
+            },
+            _ => Ok(()),
+        },
+        (None, Some(found)) => match lhs.last() {
+            Some(&(index, _)) if index >= found => {
+                Err(DimensionMismatch { expected: index + 1, found })
+            },
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Panics if the two vectors given by their dimensions and components
+/// cannot belong to the same vector space.
+fn assert_compatible<T, U>(
+    lhs_dim: Option<usize>,
+    lhs: &[(usize, T)],
+    rhs_dim: Option<usize>,
+    rhs: &[(usize, U)],
+) {
+    if let Err(error) = check_compatible(lhs_dim, lhs, rhs_dim, rhs) {
+        panic!("{}", error);
+    }
+}
+
+/// Returns the number of implicit zero components of a vector of dimension `dim`
+/// (if known) and the index of the first one (if any).
+fn implicit_zeros<T>(dim: Option<usize>, components: &[(usize, T)]) -> (usize, Option<usize>) {
+    let dim = match dim {
+        Some(dim) => dim,
+        None => return (0, None),
+    };
+    let zeros = dim - components.len();
+    if zeros == 0 {
+        return (0, None);
+    }
+    let first = components.iter().enumerate().position(|(position, &(index, _))| position != index);
+    (zeros, Some(first.unwrap_or(components.len())))
+}
+
+/// Calculates the dot-product between a sparse and a dense vector
+/// by looking up the sparse vector's components in the dense vector.
+fn dot_dense<T>(dim: Option<usize>, sparse: &[(usize, T)], dense: &[T]) -> T
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+{
+    assert_compatible::<T, T>(dim, sparse, Some(dense.len()), &[]);
+    sparse.iter().fold(T::zero(), |sum, &(index, value)| {
+        sum + (value * dense[index])
+    })
+}
+
+/// Applies `f` to each component of `dense` stored in the sparse vector
+/// and the corresponding sparse component.
+///
+/// Panics if the sparse vector does not fit the dense vector's dimension.
+#[inline]
+fn scatter_dense<T, F>(dense: &mut [T], dim: Option<usize>, sparse: &[(usize, T)], f: F)
+where
+    T: Copy,
+    F: Fn(&mut T, T),
+{
+    assert_compatible::<T, T>(Some(dense.len()), &[], dim, sparse);
+    for &(index, value) in sparse {
+        f(&mut dense[index], value);
+    }
+}
+
+/// Calculates the squared euclidian distance between a sparse and a dense vector.
+fn squared_distance_dense<T>(dim: Option<usize>, sparse: &[(usize, T)], dense: &[T]) -> T
+where
+    T: Copy + Signed,
+{
+    assert_compatible::<T, T>(dim, sparse, Some(dense.len()), &[]);
+    let mut sparse_iter = sparse.iter().peekable();
+    dense.iter().enumerate().fold(T::zero(), |sum, (index, &value)| {
+        let delta = match sparse_iter.peek() {
+            Some(&&(sparse_index, sparse_value)) if sparse_index == index => {
+                sparse_iter.next();
+                value - sparse_value
+            },
+            _ => value,
+        };
+        sum + (delta * delta)
+    })
+}
+
+/// Calculates the squared euclidian distance between a sparse and a dense vector,
+/// if it does not exceed `bound`.
+fn squared_distance_dense_bounded<T>(dim: Option<usize>, sparse: &[(usize, T)], dense: &[T], bound: T) -> Option<T>
+where
+    T: Copy + Signed + PartialOrd,
+{
+    assert_compatible::<T, T>(dim, sparse, Some(dense.len()), &[]);
+    let mut sparse_iter = sparse.iter().peekable();
+    bounded(T::zero(), bound).and_then(|zero| {
+        dense.iter().enumerate().try_fold(zero, |sum, (index, &value)| {
+            let delta = match sparse_iter.peek() {
+                Some(&&(sparse_index, sparse_value)) if sparse_index == index => {
+                    sparse_iter.next();
+                    value - sparse_value
+                },
+                _ => value,
+            };
+            bounded(sum + (delta * delta), bound)
+        })
+    })
+}
+
+/// Calculates the cosine similarity between a sparse and a dense vector.
+fn cosine_similarity_dense<T>(dim: Option<usize>, sparse: &[(usize, T)], dense: &[T]) -> T
+where
+    T: Real,
+{
+    assert_compatible::<T, T>(dim, sparse, Some(dense.len()), &[]);
+    let mut sparse_iter = sparse.iter().peekable();
+    let zero = T::zero();
+    let (dot, sparse_sum, dense_sum) = dense.iter().enumerate().fold((zero, zero, zero), |sums, (index, &value)| {
+        let (dot, sparse_sum, dense_sum) = sums;
+        let sparse_value = match sparse_iter.peek() {
+            Some(&&(sparse_index, sparse_value)) if sparse_index == index => {
+                sparse_iter.next();
+                sparse_value
+            },
+            _ => zero,
+        };
+        (
+            dot + (sparse_value * value),
+            sparse_sum + (sparse_value * sparse_value),
+            dense_sum + (value * value),
+        )
+    });
+    ::cosine_similarity(dot, sparse_sum, dense_sum)
+}
+
+/// Expands `components` into the components of a dense vector of dimension `dim`.
+#[cfg(feature = "std")]
+fn to_dense<T>(own_dim: Option<usize>, components: &[(usize, T)], dim: usize) -> Result<Vec<T>, Error>
+where
+    T: Copy + Zero,
+{
+    if let Some(expected) = own_dim {
+        if expected != dim {
+            return Err(DimensionMismatch { expected, found: dim }.into());
+        }
+    }
+    validate_dim(components, dim)?;
+    let mut dense = vec![T::zero(); dim];
+    for &(index, value) in components {
+        dense[index] = value;
+    }
+    Ok(dense)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn validate_sorted() {
+        let components = [(0, 0.1), (2, 0.2), (5, 0.3)];
+        expect!(validate(&components)).to(be_ok());
+    }
+
+    #[test]
+    fn validate_unsorted() {
+        let components = [(0, 0.1), (5, 0.2), (2, 0.3)];
+        expect!(validate(&components)).to(be_err().value(Error::UnsortedIndex { index: 2 }));
+    }
+
+    #[test]
+    fn validate_duplicate() {
+        let components = [(0, 0.1), (2, 0.2), (2, 0.3)];
+        expect!(validate(&components)).to(be_err().value(Error::DuplicateIndex { index: 2 }));
+    }
+
+    #[test]
+    fn sort_items_unsorted() {
+        let mut items = [(5, 0.1), (0, 0.2), (2, 0.3)];
+        sort_items(&mut items);
+        expect!(items).to(be_equal_to([(0, 0.2), (2, 0.3), (5, 0.1)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 occurs more than once")]
+    fn sort_items_duplicate() {
+        sort_items(&mut [(2, 0.1), (0, 0.2), (2, 0.3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate index")]
+    fn assert_unique_indices_duplicate() {
+        assert_unique_indices(&[3, 1, 3]);
+    }
+
+    #[test]
+    fn validate_dim_within() {
+        let components = [(0, 0.1), (2, 0.2), (5, 0.3)];
+        expect!(validate_dim(&components, 6)).to(be_ok());
+    }
+
+    #[test]
+    fn validate_dim_out_of_bounds() {
+        let components = [(0, 0.1), (2, 0.2), (5, 0.3)];
+        let error = Error::IndexOutOfBounds { index: 5, dim: 5 };
+        expect!(validate_dim(&components, 5)).to(be_err().value(error));
+    }
+
+    #[test]
+    fn implicit_zeros_known_dim() {
+        expect!(implicit_zeros(Some(6), &[(0, 0.1), (1, 0.2), (4, 0.3)])).to(be_equal_to((3, Some(2))));
+        expect!(implicit_zeros(Some(4), &[(0, 0.1), (1, 0.2)])).to(be_equal_to((2, Some(2))));
+        expect!(implicit_zeros(Some(2), &[(0, 0.1), (1, 0.2)])).to(be_equal_to((0, None)));
+    }
+
+    #[test]
+    fn implicit_zeros_unknown_dim() {
+        expect!(implicit_zeros(None, &[(1, 0.1), (4, 0.3)])).to(be_equal_to((0, None)));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn assert_compatible_mismatch() {
+        assert_compatible(Some(5), &[(0, 0.1)], Some(6), &[(1, 0.2)]);
+    }
+
+    #[test]
+    fn check_compatible_unknown_dims() {
+        let subject = check_compatible(None, &[(0, 0.1), (7, 0.3)], None, &[(1, 0.2)]);
+        expect!(subject).to(be_ok());
+    }
+
+    #[test]
+    fn check_compatible_out_of_bounds() {
+        let subject = check_compatible(Some(6), &[(0, 0.1)], None, &[(1, 0.2), (7, 0.3)]);
+        expect!(subject).to(be_err().value(DimensionMismatch { expected: 6, found: 8 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn assert_compatible_out_of_bounds() {
+        assert_compatible(None, &[(0, 0.1), (7, 0.3)], Some(6), &[(1, 0.2)]);
+    }
+}
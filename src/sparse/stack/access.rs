@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::Index;
+
+use arrayvec::Array;
+
+use Error;
+use sparse::StaticZero;
+use super::SparseVector;
+
+impl<T, A> SparseVector<A>
+where
+    A: Array<Item = (usize, T)>,
+{
+    /// The position of the component at `index` within `self.components`,
+    /// or the position it would have to be inserted at.
+    #[inline]
+    fn position(&self, index: usize) -> Result<usize, usize> {
+        self.components.binary_search_by_key(&index, |&(index, _)| index)
+    }
+
+    /// A reference to the component at `index`, if it is stored in `self`
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self.position(index) {
+            Ok(position) => Some(&self.components[position].1),
+            Err(_) => None,
+        }
+    }
+
+    /// A mutable reference to the component at `index`, if it is stored in `self`
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self.position(index) {
+            Ok(position) => Some(&mut self.components[position].1),
+            Err(_) => None,
+        }
+    }
+
+    /// `true` if the component at `index` is stored in `self`, otherwise `false`
+    #[inline]
+    pub fn contains(&self, index: usize) -> bool {
+        self.position(index).is_ok()
+    }
+
+    /// Sets the component at `index` to `value`, keeping the components ordered.
+    ///
+    /// Returns the component's previous value, if it was stored in `self`.
+    ///
+    /// Fails if `index` lies outside of `self.dim()`, or if storing
+    /// a new component would exceed the vector's capacity.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<Option<T>, Error> {
+        if let Some(dim) = self.dim {
+            if index >= dim {
+                return Err(Error::IndexOutOfBounds { index, dim });
+            }
+        }
+        match self.position(index) {
+            Ok(position) => {
+                Ok(Some(::std::mem::replace(&mut self.components[position].1, value)))
+            },
+            Err(position) => {
+                if self.components.is_full() {
+                    return Err(Error::CapacityExceeded { capacity: A::capacity() });
+                }
+                self.components.insert(position, (index, value));
+                Ok(None)
+            },
+        }
+    }
+
+    /// Removes the component at `index`, returning its value, if it was stored in `self`.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        match self.position(index) {
+            Ok(position) => Some(self.components.remove(position).1),
+            Err(_) => None,
+        }
+    }
+}
+
+/// Returns a reference to the component at `index`,
+/// or to zero if it is not stored in `self`.
+///
+/// Panics if `index` lies outside of `self.dim()`.
+impl<T, A> Index<usize> for SparseVector<A>
+where
+    T: StaticZero,
+    A: Array<Item = (usize, T)>,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        if let Some(dim) = self.dim {
+            if index >= dim {
+                panic!("{}", Error::IndexOutOfBounds { index, dim });
+            }
+        }
+        self.get(index).unwrap_or(T::static_zero())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn get() {
+        let subject = SparseVector::try_from([(0, 0.2), (2, 1.0), (5, 4.0)]).unwrap();
+        expect!(subject.get(2)).to(be_some().value(&1.0));
+        expect!(subject.get(3)).to(be_none());
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut subject = SparseVector::try_from([(0, 0.2), (2, 1.0), (5, 4.0)]).unwrap();
+        *subject.get_mut(5).unwrap() = 8.0;
+        expect!(subject.get_mut(3)).to(be_none());
+        expect!(subject.get(5)).to(be_some().value(&8.0));
+    }
+
+    #[test]
+    fn contains() {
+        let subject = SparseVector::try_from([(0, 0.2), (2, 1.0), (5, 4.0)]).unwrap();
+        expect!(subject.contains(2)).to(be_true());
+        expect!(subject.contains(3)).to(be_false());
+    }
+
+    #[test]
+    fn insert() {
+        type Type = SparseVector<[(usize, f32); 4]>;
+        let mut subject = Type::try_from_iter(vec![(0, 0.2), (2, 1.0)]).unwrap();
+        let expected = Type::try_from_iter(vec![(0, 0.2), (2, 2.0), (3, 3.0), (7, 7.0)]).unwrap();
+        expect!(subject.insert(3, 3.0)).to(be_ok().value(None));
+        expect!(subject.insert(7, 7.0)).to(be_ok().value(None));
+        expect!(subject.insert(2, 2.0)).to(be_ok().value(Some(1.0)));
+        expect!(subject).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn insert_exceeding_capacity() {
+        let mut subject = SparseVector::try_from([(0, 0.2), (2, 1.0)]).unwrap();
+        expect!(subject.insert(2, 2.0)).to(be_ok().value(Some(1.0)));
+        expect!(subject.insert(3, 3.0)).to(be_err().value(Error::CapacityExceeded { capacity: 2 }));
+    }
+
+    #[test]
+    fn insert_out_of_bounds() {
+        type Type = SparseVector<[(usize, f32); 4]>;
+        let mut subject = Type::try_from_iter(vec![(0, 0.2), (2, 1.0)]).unwrap().with_dim(3).unwrap();
+        expect!(subject.insert(3, 3.0)).to(be_err().value(Error::IndexOutOfBounds { index: 3, dim: 3 }));
+    }
+
+    #[test]
+    fn remove() {
+        let mut subject = SparseVector::try_from([(0, 0.2), (2, 1.0), (5, 4.0)]).unwrap();
+        expect!(subject.remove(2)).to(be_some().value(1.0));
+        expect!(subject.remove(3)).to(be_none());
+        expect!(subject.len()).to(be_equal_to(2));
+        expect!(subject.contains(2)).to(be_false());
+    }
+
+    #[test]
+    fn index() {
+        let subject = SparseVector::try_from([(0, 0.2), (2, 1.0), (5, 4.0)]).unwrap();
+        expect!(subject[2]).to(be_equal_to(1.0));
+        expect!(subject[3]).to(be_equal_to(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_out_of_bounds() {
+        let subject = SparseVector::try_from([(0, 2), (2, 1)]).unwrap().with_dim(3).unwrap();
+        let _ = subject[3];
+    }
+}
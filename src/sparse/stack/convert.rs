@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::convert::TryFrom;
+
+use num_traits::Zero;
+use arrayvec::{Array, ArrayVec};
+
+use Error;
+use dense::heap::DenseVector;
+use sparse;
+use super::SparseVector;
+
+impl<T, A> SparseVector<A>
+where
+    T: Copy + Zero,
+    A: Array<Item = (usize, T)>,
+{
+    /// Converts `self` into a dense vector of dimension `dim`.
+    ///
+    /// Fails if `dim` does not match `self.dim()`,
+    /// or if any of `self`'s indices lies outside of `dim`.
+    pub fn to_dense(&self, dim: usize) -> Result<DenseVector<T>, Error> {
+        let components = sparse::to_dense(self.dim, &self.components[..], dim)?;
+        Ok(DenseVector::from(components))
+    }
+}
+
+impl<T, A> TryFrom<sparse::heap::SparseVector<T>> for SparseVector<A>
+where
+    A: Array<Item = (usize, T)>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vector: sparse::heap::SparseVector<T>) -> Result<Self, Self::Error> {
+        let dim = vector.dim();
+        let mut components = ArrayVec::new();
+        for component in vector {
+            if components.try_push(component).is_err() {
+                return Err(Error::CapacityExceeded { capacity: A::capacity() });
+            }
+        }
+        Ok(Self { components, dim })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn to_dense() {
+        let subject = SparseVector::try_from([(1, 0.5), (3, 0.25)]).unwrap();
+        let expected = DenseVector::from(vec![0.0, 0.5, 0.0, 0.25, 0.0]);
+        expect!(subject.to_dense(5)).to(be_ok().value(expected));
+    }
+
+    #[test]
+    fn to_dense_out_of_bounds() {
+        let subject = SparseVector::try_from([(1, 0.5), (3, 0.25)]).unwrap();
+        let error = Error::IndexOutOfBounds { index: 3, dim: 2 };
+        expect!(subject.to_dense(2)).to(be_err().value(error));
+    }
+
+    #[test]
+    fn iter_dense() {
+        let subject = SparseVector::try_from([(1, 0.5), (3, 0.25)]).unwrap();
+        let expected = vec![(0, 0.0), (1, 0.5), (2, 0.0), (3, 0.25)];
+        expect!(subject.iter_dense().collect::<Vec<_>>()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn try_from_heap() {
+        let subject = sparse::heap::SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap().with_dim(4).unwrap();
+        let result: SparseVector<[(usize, f32); 2]> = SparseVector::try_from(subject).unwrap();
+        expect!(result.dim()).to(be_some().value(4));
+        expect!(result.iter().collect::<Vec<_>>()).to(be_equal_to(vec![(1, 0.5), (3, 0.25)]));
+    }
+
+    #[test]
+    fn try_from_heap_exceeding_capacity() {
+        let subject = sparse::heap::SparseVector::try_from(vec![(1, 0.5), (3, 0.25)]).unwrap();
+        let result: Result<SparseVector<[(usize, f32); 1]>, _> = SparseVector::try_from(subject);
+        expect!(result.err()).to(be_some().value(Error::CapacityExceeded { capacity: 1 }));
+    }
+}
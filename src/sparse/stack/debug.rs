@@ -16,10 +16,11 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let _ = write!(f, "[");
         for (fmt_idx, (index, value)) in self.iter().enumerate() {
-            try! {
-                if fmt_idx > 0 { write!(f, ", ({}, {:?})", index, value) }
-                else { write!(f, "({}, {:?})", index, value) }
-            };
+            if fmt_idx > 0 {
+                write!(f, ", ({}, {:?})", index, value)?;
+            } else {
+                write!(f, "({}, {:?})", index, value)?;
+            }
         }
         let _ = write!(f, "]");
         Ok(())
@@ -30,11 +31,13 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn debug() {
-        let vector = SparseVector::from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0)]);
+        let vector = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0)]).unwrap();
         let subject = format!("{:?}", vector);
         let expected = "[(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0)]";
         expect!(subject).to(be_equal_to(expected));
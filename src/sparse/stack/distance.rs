@@ -2,11 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use num_traits::Signed;
+use num_traits::{Signed, real::Real};
 use ordered_iter::OrderedMapIterator;
 use arrayvec::Array;
 
-use Distance;
+use {bounded, Distance};
+use dense;
+use sparse::{assert_compatible, squared_distance_dense, squared_distance_dense_bounded};
 use super::SparseVector;
 
 impl<T, A> Distance for SparseVector<A>
@@ -17,6 +19,48 @@ where
     type Scalar = T;
 
     fn squared_distance(&self, rhs: &Self) -> Self::Scalar {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let lhs_iter = self.iter();
+        let rhs_iter = rhs.iter();
+        lhs_iter.outer_join(rhs_iter).fold(T::zero(), |sum, (_, (lhs, rhs))| {
+            let delta = match (lhs, rhs) {
+                (Some(l), Some(r)) => l - r,
+                (Some(l), None) => l,
+                (None, Some(r)) => -r,
+                _ => unreachable!(),
+            };
+            sum + (delta * delta)
+        })
+    }
+
+    fn squared_distance_bounded(&self, rhs: &Self, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        T: PartialOrd,
+    {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let lhs_iter = self.iter();
+        let rhs_iter = rhs.iter();
+        bounded(T::zero(), bound).and_then(|zero| {
+            lhs_iter.outer_join(rhs_iter).try_fold(zero, |sum, (_, (lhs, rhs))| {
+                let delta = lhs.unwrap_or_else(T::zero) - rhs.unwrap_or_else(T::zero);
+                bounded(sum + (delta * delta), bound)
+            })
+        })
+    }
+}
+
+impl<T, A> SparseVector<A>
+where
+    T: Copy + Signed,
+    A: Array<Item = (usize, T)>,
+{
+    /// Calculates the squared euclidian distance between `self` and `rhs`,
+    /// taking only components present in both vectors into account.
+    ///
+    /// Unlike `Distance::squared_distance` this ignores any component
+    /// stored in only one of the two vectors.
+    pub fn shared_support_squared_distance(&self, rhs: &Self) -> T {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
         let lhs_iter = self.iter();
         let rhs_iter = rhs.iter();
         lhs_iter.inner_join_map(rhs_iter).fold(T::zero(), |sum, (_, (lhs, rhs))| {
@@ -24,27 +68,176 @@ where
             sum + (delta * delta)
         })
     }
+
+    /// Calculates the euclidian distance between `self` and `rhs`,
+    /// taking only components present in both vectors into account.
+    pub fn shared_support_distance(&self, rhs: &Self) -> T
+    where
+        T: Real,
+    {
+        self.shared_support_squared_distance(rhs).sqrt()
+    }
+}
+
+impl<T, A, B> Distance<dense::stack::DenseVector<B>> for SparseVector<A>
+where
+    T: Copy + Signed,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn squared_distance(&self, rhs: &dense::stack::DenseVector<B>) -> Self::Scalar {
+        squared_distance_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+
+    fn squared_distance_bounded(&self, rhs: &dense::stack::DenseVector<B>, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        T: PartialOrd,
+    {
+        squared_distance_dense_bounded(self.dim, &self.components[..], rhs.as_slice(), bound)
+    }
+}
+
+impl<T, A, B> Distance<SparseVector<A>> for dense::stack::DenseVector<B>
+where
+    T: Copy + Signed,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn squared_distance(&self, rhs: &SparseVector<A>) -> Self::Scalar {
+        squared_distance_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+
+    fn squared_distance_bounded(&self, rhs: &SparseVector<A>, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        T: PartialOrd,
+    {
+        squared_distance_dense_bounded(rhs.dim, &rhs.components[..], self.as_slice(), bound)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Distance<dense::heap::DenseVector<T>> for SparseVector<A>
+where
+    T: Copy + Signed,
+    A: Array<Item = (usize, T)>,
+{
+    type Scalar = T;
+
+    fn squared_distance(&self, rhs: &dense::heap::DenseVector<T>) -> Self::Scalar {
+        squared_distance_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+
+    fn squared_distance_bounded(&self, rhs: &dense::heap::DenseVector<T>, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        T: PartialOrd,
+    {
+        squared_distance_dense_bounded(self.dim, &self.components[..], rhs.as_slice(), bound)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Distance<SparseVector<A>> for dense::heap::DenseVector<T>
+where
+    T: Copy + Signed,
+    A: Array<Item = (usize, T)>,
+{
+    type Scalar = T;
+
+    fn squared_distance(&self, rhs: &SparseVector<A>) -> Self::Scalar {
+        squared_distance_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+
+    fn squared_distance_bounded(&self, rhs: &SparseVector<A>, bound: Self::Scalar) -> Option<Self::Scalar>
+    where
+        T: PartialOrd,
+    {
+        squared_distance_dense_bounded(rhs.dim, &rhs.components[..], self.as_slice(), bound)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn squared_distance() {
-        let subject = SparseVector::from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]);
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
         let squared_distance = subject.squared_distance(&other);
-        expect!(squared_distance).to(be_close_to(13.76));
+        expect!(squared_distance).to(be_close_to(18.14));
+    }
+
+    #[test]
+    fn squared_distance_bounded() {
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        expect!(subject.squared_distance_bounded(&other, 20.0).unwrap()).to(be_close_to(18.14));
+        expect!(subject.squared_distance_bounded(&other, 18.0)).to(be_none());
     }
 
     #[test]
     fn distance() {
-        let subject = SparseVector::from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]);
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
         let distance = subject.distance(&other);
+        expect!(distance).to(be_close_to(4.259));
+    }
+
+    #[test]
+    fn shared_support_squared_distance() {
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let squared_distance = subject.shared_support_squared_distance(&other);
+        expect!(squared_distance).to(be_close_to(13.76));
+    }
+
+    #[test]
+    fn shared_support_distance() {
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let distance = subject.shared_support_distance(&other);
         expect!(distance).to(be_close_to(3.71));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn squared_distance_out_of_bounds() {
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5)]).unwrap().with_dim(2).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2)]).unwrap();
+        let _ = subject.squared_distance(&other);
+    }
+
+    #[test]
+    fn squared_distance_dense() {
+        let subject = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::stack::DenseVector::from([0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.squared_distance(&other)).to(be_close_to(18.14));
+        expect!(other.squared_distance(&subject)).to(be_close_to(18.14));
+    }
+
+    #[test]
+    fn squared_distance_dense_bounded() {
+        let subject = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::stack::DenseVector::from([0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.squared_distance_bounded(&other, 20.0).unwrap()).to(be_close_to(18.14));
+        expect!(subject.squared_distance_bounded(&other, 18.0)).to(be_none());
+        expect!(other.squared_distance_bounded(&subject, 20.0).unwrap()).to(be_close_to(18.14));
+        expect!(other.squared_distance_bounded(&subject, 18.0)).to(be_none());
+    }
+
+    #[test]
+    fn distance_heap_dense() {
+        let subject = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::heap::DenseVector::from(vec![0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.distance(&other)).to(be_close_to(4.259));
+        expect!(other.distance(&subject)).to(be_close_to(4.259));
+    }
 }
@@ -47,20 +47,22 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn div() {
-        let subject = SparseVector::from([(0, 0.4), (1, 1.0), (2, 2.0), (4, 4.0), (5, 8.0)]);
-        let expected = SparseVector::from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
+        let subject = SparseVector::try_from([(0, 0.4), (1, 1.0), (2, 2.0), (4, 4.0), (5, 8.0)]).unwrap();
+        let expected = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
         let result = subject / 2.0;
         expect!(result).to(be_equal_to(expected));
     }
 
     #[test]
     fn div_assign() {
-        let subject = SparseVector::from([(0, 0.4), (1, 1.0), (2, 2.0), (4, 4.0), (5, 8.0)]);
-        let expected = SparseVector::from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
+        let subject = SparseVector::try_from([(0, 0.4), (1, 1.0), (2, 2.0), (4, 4.0), (5, 8.0)]).unwrap();
+        let expected = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
 
         let mut result = subject;
         result /= 2.0;
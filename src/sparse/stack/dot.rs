@@ -9,6 +9,8 @@ use ordered_iter::OrderedMapIterator;
 use arrayvec::Array;
 
 use Dot;
+use dense;
+use sparse::{assert_compatible, dot_dense};
 use super::SparseVector;
 
 impl<T, A> Dot for SparseVector<A>
@@ -19,6 +21,7 @@ where
     type Scalar = T;
 
     fn dot(&self, rhs: &Self) -> Self::Scalar {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
         let lhs_iter = self.iter();
         let rhs_iter = rhs.iter();
         lhs_iter.inner_join_map(rhs_iter).fold(T::zero(), |sum, (_, (lhs, rhs))| {
@@ -27,18 +30,96 @@ where
     }
 }
 
+impl<T, A, B> Dot<dense::stack::DenseVector<B>> for SparseVector<A>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &dense::stack::DenseVector<B>) -> Self::Scalar {
+        dot_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+}
+
+impl<T, A, B> Dot<SparseVector<A>> for dense::stack::DenseVector<B>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &SparseVector<A>) -> Self::Scalar {
+        dot_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Dot<dense::heap::DenseVector<T>> for SparseVector<A>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+    A: Array<Item = (usize, T)>,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &dense::heap::DenseVector<T>) -> Self::Scalar {
+        dot_dense(self.dim, &self.components[..], rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Dot<SparseVector<A>> for dense::heap::DenseVector<T>
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T> + Zero,
+    A: Array<Item = (usize, T)>,
+{
+    type Scalar = T;
+
+    fn dot(&self, rhs: &SparseVector<A>) -> Self::Scalar {
+        dot_dense(rhs.dim, &rhs.components[..], self.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use expectest::prelude::*;
 
     #[test]
     fn dot() {
-        let subject = SparseVector::from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]);
-        let other = SparseVector::from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]);
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5), (2, 1.0), (4, 2.0), (5, 4.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
 
         let dot = subject.dot(&other);
         expect!(dot).to(be_close_to(1.85));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn dot_dimension_mismatch() {
+        let subject = SparseVector::try_from([(0, 0.2), (1, 0.5)]).unwrap().with_dim(2).unwrap();
+        let other = SparseVector::try_from([(1, 0.1), (2, 0.2)]).unwrap().with_dim(3).unwrap();
+        let _ = subject.dot(&other);
+    }
+
+    #[test]
+    fn dot_dense() {
+        let subject = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::stack::DenseVector::from([0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.dot(&other)).to(be_close_to(1.85));
+        expect!(other.dot(&subject)).to(be_close_to(1.85));
+    }
+
+    #[test]
+    fn dot_heap_dense() {
+        let subject = SparseVector::try_from([(1, 0.1), (2, 0.2), (3, 0.3), (5, 0.4), (6, 0.5)]).unwrap();
+        let other = dense::heap::DenseVector::from(vec![0.2, 0.5, 1.0, 0.0, 2.0, 4.0, 0.0]);
+        expect!(subject.dot(&other)).to(be_close_to(1.85));
+        expect!(other.dot(&subject)).to(be_close_to(1.85));
+    }
 }
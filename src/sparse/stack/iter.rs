@@ -8,17 +8,22 @@ use arrayvec::{Array, ArrayVec};
 
 use super::SparseVector;
 
-pub use sparse::iter::{IntoIter, Iter};
+pub use sparse::iter::{IntoIter, Iter, DenseIter};
 
+/// Collects components into a `SparseVector`.
+///
+/// Panics if the components' indices are not strictly increasing,
+/// or if their number exceeds the vector's capacity.
 impl<T, A> FromIterator<(usize, T)> for SparseVector<A>
 where
     A: Array<Item = (usize, T)>,
 {
     #[inline]
     fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
-        let mut components = ArrayVec::new();
-        components.extend(iter);
-        Self { components }
+        match SparseVector::try_from_iter(iter) {
+            Ok(vector) => vector,
+            Err(error) => panic!("{}", error),
+        }
     }
 }
 
@@ -53,6 +58,7 @@ where
 mod test {
     use super::*;
 
+    use std::convert::TryFrom;
     use std::iter::{IntoIterator, FromIterator};
 
     use expectest::prelude::*;
@@ -65,10 +71,22 @@ mod test {
         expect!(subject.components).to(be_equal_to(expected));
     }
 
+    #[test]
+    #[should_panic(expected = "index 1 occurs more than once")]
+    fn from_iter_duplicate() {
+        let _: SparseVector<[(usize, f32); 4]> = SparseVector::from_iter(vec![(0, 0.1), (1, 0.2), (1, 0.3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds capacity")]
+    fn from_iter_exceeding_capacity() {
+        let _: SparseVector<[(usize, f32); 2]> = SparseVector::from_iter(vec![(0, 0.1), (1, 0.2), (2, 0.3)]);
+    }
+
     #[test]
     fn into_iter() {
         let values = vec![(0, 0.1), (1, 0.2), (2, 0.3), (4, 0.4), (5, 0.5)];
-        let subject = SparseVector::from([(0, 0.1), (1, 0.2), (2, 0.3), (4, 0.4), (5, 0.5)]);
+        let subject = SparseVector::try_from([(0, 0.1), (1, 0.2), (2, 0.3), (4, 0.4), (5, 0.5)]).unwrap();
         let output: Vec<_> = subject.into_iter().collect();
         expect!(output).to(be_equal_to(values));
     }
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Zero;
+use ordered_iter::OrderedMapIterator;
+use arrayvec::Array;
+
+use sparse::assert_compatible;
+use super::SparseVector;
+
+impl<T, A> SparseVector<A>
+where
+    T: Copy + Zero,
+    A: Array<Item = (usize, T)>,
+{
+    /// Applies `f` to each of `self`'s stored components.
+    ///
+    /// Components missing from `self` stay zero, regardless of `f(0)`,
+    /// and components for which `f` returns zero are dropped.
+    pub fn map_nonzero<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let components = self.components.into_iter().filter_map(|(index, value)| {
+            let value = f(value);
+            if value.is_zero() { None } else { Some((index, value)) }
+        }).collect();
+        SparseVector { components, dim: self.dim }
+    }
+
+    /// Applies `f` to each pair of corresponding components of `self` and `rhs`,
+    /// substituting zero for components stored in only one of them.
+    ///
+    /// Components missing from both stay zero, regardless of `f(0, 0)`,
+    /// and components for which `f` returns zero are dropped.
+    /// Panics if the number of resulting components exceeds `self`'s capacity.
+    ///
+    /// Panics if `self` and `rhs` have mismatching dimensions.
+    pub fn zip_with<F>(self, rhs: &Self, mut f: F) -> Self
+    where
+        F: FnMut(T, T) -> T,
+    {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
+        let components = {
+            let lhs_iter = self.iter();
+            let rhs_iter = rhs.iter();
+            lhs_iter.outer_join(rhs_iter).filter_map(|(index, (lhs, rhs))| {
+                let value = f(lhs.unwrap_or_else(T::zero), rhs.unwrap_or_else(T::zero));
+                if value.is_zero() { None } else { Some((index, value)) }
+            }).collect()
+        };
+        SparseVector { components, dim: self.dim.or(rhs.dim) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn map_nonzero() {
+        let subject = SparseVector::try_from([(0, 1.0), (2, -2.0), (5, 3.0)]).unwrap();
+        let expected = SparseVector::try_from([(0, 2.0), (5, 6.0)]).unwrap();
+        let result = subject.map_nonzero(|value| if value < 0.0 { 0.0 } else { value * 2.0 });
+        expect!(result.len()).to(be_equal_to(2));
+        expect!(result.iter().collect::<Vec<_>>()).to(be_equal_to(expected.iter().collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn zip_with() {
+        let subject = SparseVector::try_from([(0, 1.0), (2, 5.0), (5, 3.0)]).unwrap();
+        let other = SparseVector::try_from([(1, 2.0), (2, -4.0), (5, -3.0)]).unwrap();
+        let expected = vec![(0, 1.0), (1, 2.0), (2, 1.0)];
+        let result = subject.zip_with(&other, |lhs, rhs| lhs + rhs);
+        expect!(result.iter().collect::<Vec<_>>()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn zip_with_out_of_bounds() {
+        let subject = SparseVector::try_from([(0, 1.0), (2, 5.0), (5, 3.0)]).unwrap().with_dim(6).unwrap();
+        let other = SparseVector::try_from([(1, 2.0), (2, -4.0), (6, -3.0)]).unwrap();
+        let _ = subject.zip_with(&other, |lhs, rhs| lhs + rhs);
+    }
+}
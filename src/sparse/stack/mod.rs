@@ -4,27 +4,47 @@
 
 //! Sparse stack-allocated vector representation.
 
+use std::convert::TryFrom;
 use std::ops::{Add, Sub, Mul, Div};
 use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
 
 use num_traits::{NumAssign, MulAdd, MulAddAssign};
 use arrayvec::{Array, ArrayVec};
 
-use {Vector, VectorOps, VectorAssignOps};
+use {Error, Vector, VectorOps, VectorAssignOps};
+
+mod access;
 
 mod add;
 mod sub;
 mod mul;
 mod div;
 mod mul_add;
+mod hadamard;
+mod neg;
+mod map;
+mod math;
 
 mod dot;
 mod distance;
+mod norm;
+mod cosine;
+mod correlation;
+mod set;
+mod stats;
+#[cfg(feature = "std")]
+mod select;
+mod checked;
+
+#[cfg(feature = "std")]
+mod convert;
+#[cfg(feature = "serde")]
+mod serde;
 
 mod debug;
 mod iter;
 
-pub use self::iter::{Iter, IntoIter};
+pub use self::iter::{Iter, IntoIter, DenseIter};
 
 /// A sparse stack-allocated multi-dimensional vector.
 pub struct SparseVector<A>
@@ -32,12 +52,58 @@ where
     A: Array,
 {
     components: ArrayVec<A>,
+    dim: Option<usize>,
 }
 
 impl<T, A> SparseVector<A>
 where
     A: Array<Item = (usize, T)>,
 {
+    /// Creates a `SparseVector` from `items` without validating them.
+    ///
+    /// The caller must ensure that the indices of `items` are strictly increasing,
+    /// as otherwise operations on the vector produce meaningless results.
+    #[inline]
+    pub fn from_sorted_unchecked(items: A) -> Self {
+        let components = ArrayVec::from(items);
+        debug_assert!(super::validate(&components[..]).is_ok());
+        Self { components, dim: None }
+    }
+
+    /// Creates a `SparseVector` from the components yielded by `iter`.
+    ///
+    /// Fails if the components' indices are not strictly increasing,
+    /// or if their number exceeds the vector's capacity.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (usize, T)>,
+    {
+        let mut components = ArrayVec::new();
+        for component in iter {
+            if components.try_push(component).is_err() {
+                return Err(Error::CapacityExceeded { capacity: A::capacity() });
+            }
+        }
+        super::validate(&components[..])?;
+        Ok(Self { components, dim: None })
+    }
+
+    /// Sets the dimension of the vector space `self` belongs to.
+    ///
+    /// Fails if any of `self`'s indices lies outside of `dim`.
+    #[inline]
+    pub fn with_dim(mut self, dim: usize) -> Result<Self, Error> {
+        super::validate_dim(&self.components[..], dim)?;
+        self.dim = Some(dim);
+        Ok(self)
+    }
+
+    /// The dimension of the vector space `self` belongs to, if known
+    #[inline]
+    pub fn dim(&self) -> Option<usize> {
+        self.dim
+    }
+
     /// The number of components in `self`
     #[inline]
     pub fn len(&self) -> usize {
@@ -55,6 +121,21 @@ where
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter::new(&self.components[..])
     }
+
+    /// A borrowing iterator over all of `self`'s components, including zeros
+    ///
+    /// Iterates up to `self.dim()`, or up to the last non-zero component if the
+    /// dimension is unknown.
+    #[inline]
+    pub fn iter_dense<'a>(&'a self) -> DenseIter<'a, T>
+    where
+        T: Copy,
+    {
+        let dim = self.dim.unwrap_or_else(|| {
+            self.components.last().map_or(0, |&(index, _)| index + 1)
+        });
+        DenseIter::new(&self.components[..], dim)
+    }
 }
 
 impl<T, A> Clone for SparseVector<A>
@@ -64,7 +145,7 @@ where
 {
     fn clone(&self) -> Self {
         let components = self.components.clone();
-        Self { components }
+        Self { components, dim: self.dim }
     }
 }
 
@@ -74,17 +155,32 @@ where
     A: Array<Item = (usize, T)>,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.components.eq(&other.components)
+        self.dim == other.dim && self.components.eq(&other.components)
+    }
+}
+
+impl<T, const N: usize> TryFrom<[(usize, T); N]> for SparseVector<[(usize, T); N]>
+where
+    [(usize, T); N]: Array<Item = (usize, T)>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(items: [(usize, T); N]) -> Result<Self, Self::Error> {
+        Self::try_from(ArrayVec::from(items))
     }
 }
 
-impl<T, A> From<A> for SparseVector<A>
+impl<T, A> TryFrom<ArrayVec<A>> for SparseVector<A>
 where
     A: Array<Item = (usize, T)>,
 {
+    type Error = Error;
+
     #[inline]
-    fn from(items: A) -> Self {
-        Self { components: ArrayVec::from(items) }
+    fn try_from(components: ArrayVec<A>) -> Result<Self, Self::Error> {
+        super::validate(&components[..])?;
+        Ok(Self { components, dim: None })
     }
 }
 
@@ -118,9 +214,62 @@ mod test {
     use expectest::prelude::*;
 
     #[test]
-    fn from() {
+    fn try_from() {
         const VALUES: [(usize, f32); 5] = [(0, 0.0), (1, 1.0), (2, 0.5), (4, 0.25), (8, 0.125)];
-        let subject = SparseVector::from(VALUES.clone());
+        let subject = SparseVector::try_from(VALUES).unwrap();
+        let expected = ArrayVec::from(VALUES);
+        expect!(subject.components).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn try_from_unsorted() {
+        let subject = SparseVector::try_from([(0, 0.0), (4, 1.0), (2, 0.5)]);
+        expect!(subject).to(be_err().value(Error::UnsortedIndex { index: 2 }));
+    }
+
+    #[test]
+    fn try_from_duplicate() {
+        let subject = SparseVector::try_from([(0, 0.0), (2, 1.0), (2, 0.5)]);
+        expect!(subject).to(be_err().value(Error::DuplicateIndex { index: 2 }));
+    }
+
+    #[test]
+    fn try_from_iter() {
+        let values = vec![(0, 0.0), (1, 1.0), (4, 0.5)];
+        let subject: SparseVector<[(usize, f32); 4]> = SparseVector::try_from_iter(values.clone()).unwrap();
+        expect!(subject.components.to_vec()).to(be_equal_to(values));
+    }
+
+    #[test]
+    fn try_from_iter_exceeding_capacity() {
+        let values = vec![(0, 0.0), (1, 1.0), (4, 0.5)];
+        let subject: Result<SparseVector<[(usize, f32); 2]>, _> = SparseVector::try_from_iter(values);
+        expect!(subject).to(be_err().value(Error::CapacityExceeded { capacity: 2 }));
+    }
+
+    #[test]
+    fn try_from_iter_unsorted() {
+        let values = vec![(0, 0.0), (4, 1.0), (1, 0.5)];
+        let subject: Result<SparseVector<[(usize, f32); 4]>, _> = SparseVector::try_from_iter(values);
+        expect!(subject).to(be_err().value(Error::UnsortedIndex { index: 1 }));
+    }
+
+    #[test]
+    fn with_dim() {
+        let subject = SparseVector::try_from([(0, 0.0), (2, 1.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.dim()).to(be_some().value(4));
+    }
+
+    #[test]
+    fn with_dim_out_of_bounds() {
+        let subject = SparseVector::try_from([(0, 0.0), (2, 1.0)]).unwrap().with_dim(2);
+        expect!(subject).to(be_err().value(Error::IndexOutOfBounds { index: 2, dim: 2 }));
+    }
+
+    #[test]
+    fn from_sorted_unchecked() {
+        const VALUES: [(usize, f32); 3] = [(0, 0.0), (1, 1.0), (4, 0.5)];
+        let subject = SparseVector::from_sorted_unchecked(VALUES);
         let expected = ArrayVec::from(VALUES);
         expect!(subject.components).to(be_equal_to(expected));
     }
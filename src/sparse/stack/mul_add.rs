@@ -8,36 +8,58 @@ use num_traits::Zero;
 use ordered_iter::OrderedMapIterator;
 use arrayvec::Array;
 
+use sparse::assert_compatible;
 use super::SparseVector;
 
-impl<T, A, V> MulAdd<T, V> for SparseVector<A>
+impl<T, A> MulAdd<T, SparseVector<A>> for SparseVector<A>
 where
     T: Copy + Zero + MulAdd<T, T, Output = T>,
     A: Array<Item = (usize, T)>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     type Output = Self;
 
     #[inline]
-    fn mul_add(mut self, a: T, b: V) -> Self::Output {
+    fn mul_add(mut self, a: T, b: SparseVector<A>) -> Self::Output {
+        self.mul_add_assign(a, &b);
+        self
+    }
+}
+
+impl<'a, T, A> MulAdd<T, &'a SparseVector<A>> for SparseVector<A>
+where
+    T: Copy + Zero + MulAdd<T, T, Output = T>,
+    A: Array<Item = (usize, T)>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(mut self, a: T, b: &'a SparseVector<A>) -> Self::Output {
         self.mul_add_assign(a, b);
         self
     }
 }
 
-impl<T, A, V> MulAddAssign<T, V> for SparseVector<A>
+impl<T, A> MulAddAssign<T, SparseVector<A>> for SparseVector<A>
+where
+    T: Copy + Zero + MulAdd<T, T, Output = T>,
+    A: Array<Item = (usize, T)>,
+{
+    #[inline]
+    fn mul_add_assign(&mut self, a: T, b: SparseVector<A>) {
+        self.mul_add_assign(a, &b);
+    }
+}
+
+impl<'a, T, A> MulAddAssign<T, &'a SparseVector<A>> for SparseVector<A>
 where
     T: Copy + Zero + MulAdd<T, T, Output = T>,
     A: Array<Item = (usize, T)>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     #[inline]
-    fn mul_add_assign(&mut self, a: T, b: V) {
+    fn mul_add_assign(&mut self, a: T, b: &'a SparseVector<A>) {
+        assert_compatible(self.dim, &self.components[..], b.dim, &b.components[..]);
         self.components = {
-            let iter = b.into_iter();
-            let outer_join = self.iter().outer_join(iter);
+            let outer_join = self.iter().outer_join(b.iter());
             outer_join.filter_map(|(index, (lhs, rhs))| {
                     let value = match (lhs, rhs) {
                         (Some(l), Some(r)) => l.mul_add(a, r),
@@ -53,6 +75,7 @@ where
                 })
                 .collect()
         };
+        self.dim = self.dim.or(b.dim);
     }
 }
 
@@ -94,4 +117,20 @@ mod test {
         result.mul_add_assign(2.0, &other);
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn mul_add_dimension_mismatch() {
+        let subject = Type::from_iter(vec![(0, 0.2), (1, 0.5)]).with_dim(5).unwrap();
+        let other = Type::from_iter(vec![(1, 0.1), (2, 0.2)]).with_dim(3).unwrap();
+        let _ = subject.mul_add(2.0, &other);
+    }
+
+    #[test]
+    fn mul_add_propagates_dim() {
+        let subject = Type::from_iter(vec![(0, 0.2), (1, 0.5)]);
+        let other = Type::from_iter(vec![(1, 0.1), (2, 0.2)]).with_dim(5).unwrap();
+        let result = subject.mul_add(2.0, other);
+        expect!(result.dim()).to(be_some().value(5));
+    }
 }
@@ -0,0 +1,41 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::Neg;
+
+use arrayvec::Array;
+
+use super::SparseVector;
+
+impl<T, A> Neg for SparseVector<A>
+where
+    T: Copy + Neg<Output = T>,
+    A: Array<Item = (usize, T)>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        for component in self.components.iter_mut() {
+            component.1 = -component.1;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn neg() {
+        let subject = SparseVector::try_from([(0, 1.0), (2, -2.0)]).unwrap().with_dim(4).unwrap();
+        let expected = SparseVector::try_from([(0, -1.0), (2, 2.0)]).unwrap().with_dim(4).unwrap();
+        expect!(-subject).to(be_equal_to(expected));
+    }
+}
@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::Signed;
+use arrayvec::Array;
+
+use {Selection, select, sparsify, ascending, descending};
+use sparse;
+use super::SparseVector;
+
+impl<T, A> Selection for SparseVector<A>
+where
+    T: Copy + PartialOrd,
+    A: Array<Item = (usize, T)>,
+{
+    type Scalar = T;
+
+    fn top_k(&self, k: usize) -> Vec<(usize, T)> {
+        select(self.iter(), k, descending)
+    }
+
+    fn bottom_k(&self, k: usize) -> Vec<(usize, T)> {
+        select(self.iter(), k, ascending)
+    }
+
+    fn top_k_by_magnitude(&self, k: usize) -> Vec<(usize, T)>
+    where
+        T: Signed,
+    {
+        select(self.iter(), k, |lhs, rhs| descending(&lhs.abs(), &rhs.abs()))
+    }
+
+    fn argsort(&self) -> Vec<(usize, T)> {
+        select(self.iter(), usize::MAX, ascending)
+    }
+
+    fn sparsify_top_k(&self, k: usize) -> sparse::heap::SparseVector<T>
+    where
+        T: Signed,
+    {
+        sparsify(self.iter(), k, self.dim)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::f64;
+    use std::convert::TryFrom;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn top_k() {
+        let subject = SparseVector::try_from([(0, 1.0), (1, -5.0), (4, 3.0), (6, 4.0)]).unwrap();
+        expect!(subject.top_k(2)).to(be_equal_to(vec![(6, 4.0), (4, 3.0)]));
+        expect!(subject.top_k(10).len()).to(be_equal_to(4));
+    }
+
+    #[test]
+    fn bottom_k() {
+        let subject = SparseVector::try_from([(0, 1.0), (1, -5.0), (4, 3.0), (6, 4.0)]).unwrap();
+        expect!(subject.bottom_k(2)).to(be_equal_to(vec![(1, -5.0), (0, 1.0)]));
+    }
+
+    #[test]
+    fn top_k_by_magnitude() {
+        let subject = SparseVector::try_from([(0, 1.0), (1, -5.0), (4, 3.0), (6, 4.0)]).unwrap();
+        expect!(subject.top_k_by_magnitude(2)).to(be_equal_to(vec![(1, -5.0), (6, 4.0)]));
+    }
+
+    #[test]
+    fn argsort() {
+        let subject = SparseVector::try_from([(0, 1.0), (1, f64::NAN), (4, 3.0), (6, -4.0)]).unwrap();
+        let sorted = subject.argsort();
+        let indices: Vec<_> = sorted.iter().map(|&(index, _)| index).collect();
+        expect!(indices).to(be_equal_to(vec![6, 0, 4, 1]));
+    }
+
+    #[test]
+    fn sparsify_top_k() {
+        let subject = SparseVector::try_from([(0, 1.0), (1, -5.0), (4, 0.0)]).unwrap().with_dim(8).unwrap();
+        let expected = sparse::heap::SparseVector::try_from(vec![(1, -5.0)]).unwrap().with_dim(8).unwrap();
+        expect!(subject.sparsify_top_k(1)).to(be_equal_to(expected));
+    }
+}
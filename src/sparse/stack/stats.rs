@@ -0,0 +1,183 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use num_traits::{Zero, Float};
+use arrayvec::Array;
+
+use {Statistics, sum, moments, extremum};
+use sparse::implicit_zeros;
+use super::SparseVector;
+
+impl<T, A> Statistics for SparseVector<A>
+where
+    T: Copy + PartialOrd + Zero,
+    A: Array<Item = (usize, T)>,
+{
+    type Scalar = T;
+
+    fn nnz(&self) -> usize {
+        self.iter().filter(|&(_, value)| !value.is_zero()).count()
+    }
+
+    fn sum(&self) -> Self::Scalar {
+        sum(self.iter(), false)
+    }
+
+    fn nan_sum(&self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        sum(self.iter(), true)
+    }
+
+    fn mean(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        let (zeros, _) = implicit_zeros(self.dim, &self.components[..]);
+        moments(self.iter(), zeros, false).map(|(mean, _)| mean)
+    }
+
+    fn nan_mean(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        let (zeros, _) = implicit_zeros(self.dim, &self.components[..]);
+        moments(self.iter(), zeros, true).map(|(mean, _)| mean)
+    }
+
+    fn variance(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        let (zeros, _) = implicit_zeros(self.dim, &self.components[..]);
+        moments(self.iter(), zeros, false).map(|(_, variance)| variance)
+    }
+
+    fn nan_variance(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        let (zeros, _) = implicit_zeros(self.dim, &self.components[..]);
+        moments(self.iter(), zeros, true).map(|(_, variance)| variance)
+    }
+
+    fn min(&self) -> Option<Self::Scalar> {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, false, |lhs, rhs| lhs < rhs).map(|(_, value)| value)
+    }
+
+    fn nan_min(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, true, |lhs, rhs| lhs < rhs).map(|(_, value)| value)
+    }
+
+    fn max(&self) -> Option<Self::Scalar> {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, false, |lhs, rhs| lhs > rhs).map(|(_, value)| value)
+    }
+
+    fn nan_max(&self) -> Option<Self::Scalar>
+    where
+        Self::Scalar: Float,
+    {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, true, |lhs, rhs| lhs > rhs).map(|(_, value)| value)
+    }
+
+    fn argmin(&self) -> Option<usize> {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, false, |lhs, rhs| lhs < rhs).map(|(index, _)| index)
+    }
+
+    fn nan_argmin(&self) -> Option<usize>
+    where
+        Self::Scalar: Float,
+    {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, true, |lhs, rhs| lhs < rhs).map(|(index, _)| index)
+    }
+
+    fn argmax(&self) -> Option<usize> {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, false, |lhs, rhs| lhs > rhs).map(|(index, _)| index)
+    }
+
+    fn nan_argmax(&self) -> Option<usize>
+    where
+        Self::Scalar: Float,
+    {
+        let (_, zero) = implicit_zeros(self.dim, &self.components[..]);
+        extremum(self.iter(), zero, true, |lhs, rhs| lhs > rhs).map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use std::f64;
+
+    use expectest::prelude::*;
+
+    #[test]
+    fn sum() {
+        let subject = SparseVector::try_from([(1, 1.0), (3, -2.0), (4, 0.0), (6, 4.0)]).unwrap();
+        expect!(subject.sum()).to(be_close_to(3.0));
+        expect!(subject.nnz()).to(be_equal_to(3));
+    }
+
+    #[test]
+    fn mean_variance_known_dim() {
+        let subject = SparseVector::try_from([(1, 2.0), (3, 4.0), (6, 6.0)]).unwrap().with_dim(8).unwrap();
+        expect!(subject.mean().unwrap()).to(be_close_to(1.5));
+        expect!(subject.variance().unwrap()).to(be_close_to(4.75));
+    }
+
+    #[test]
+    fn mean_variance_unknown_dim() {
+        let subject = SparseVector::try_from([(1, 2.0), (3, 4.0), (6, 6.0)]).unwrap();
+        expect!(subject.mean().unwrap()).to(be_close_to(4.0));
+        expect!(subject.variance().unwrap()).to(be_close_to(8.0 / 3.0));
+    }
+
+    #[test]
+    fn min_max_known_dim() {
+        let subject = SparseVector::try_from([(0, 3.0), (1, 2.0), (4, 5.0)]).unwrap().with_dim(6).unwrap();
+        expect!(subject.min()).to(be_some().value(0.0));
+        expect!(subject.argmin()).to(be_some().value(2));
+        expect!(subject.max()).to(be_some().value(5.0));
+        expect!(subject.argmax()).to(be_some().value(4));
+    }
+
+    #[test]
+    fn min_max_unknown_dim() {
+        let subject = SparseVector::try_from([(0, 3.0), (1, 2.0), (4, 5.0)]).unwrap();
+        expect!(subject.min()).to(be_some().value(2.0));
+        expect!(subject.argmin()).to(be_some().value(1));
+    }
+
+    #[test]
+    fn argmax_tied_with_implicit_zero() {
+        let subject = SparseVector::try_from([(0, -1.0), (3, 0.0)]).unwrap().with_dim(4).unwrap();
+        expect!(subject.max()).to(be_some().value(0.0));
+        expect!(subject.argmax()).to(be_some().value(1));
+    }
+
+    #[test]
+    fn nan() {
+        let subject = SparseVector::try_from([(0, 3.0), (2, f64::NAN), (3, -2.0)]).unwrap().with_dim(5).unwrap();
+        expect!(subject.sum().is_nan()).to(be_true());
+        expect!(subject.argmax()).to(be_some().value(2));
+        expect!(subject.nan_sum()).to(be_close_to(1.0));
+        expect!(subject.nan_mean().unwrap()).to(be_close_to(0.25));
+        expect!(subject.nan_min()).to(be_some().value(-2.0));
+        expect!(subject.nan_argmax()).to(be_some().value(0));
+    }
+}
@@ -8,36 +8,59 @@ use num_traits::Zero;
 use ordered_iter::OrderedMapIterator;
 use arrayvec::Array;
 
+use dense;
+use sparse::{assert_compatible, scatter_dense};
 use super::SparseVector;
 
-impl<T, A, V> Sub<V> for SparseVector<A>
+impl<T, A> Sub<SparseVector<A>> for SparseVector<A>
 where
     T: Copy + Zero + Sub<T, Output = T>,
     A: Array<Item = (usize, T)>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     type Output = Self;
 
     #[inline]
-    fn sub(mut self, rhs: V) -> Self::Output {
+    fn sub(mut self, rhs: SparseVector<A>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A> Sub<&'a SparseVector<A>> for SparseVector<A>
+where
+    T: Copy + Zero + Sub<T, Output = T>,
+    A: Array<Item = (usize, T)>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &'a SparseVector<A>) -> Self::Output {
         self.sub_assign(rhs);
         self
     }
 }
 
-impl<T, A, V> SubAssign<V> for SparseVector<A>
+impl<T, A> SubAssign<SparseVector<A>> for SparseVector<A>
+where
+    T: Copy + Zero + Sub<T, Output = T>,
+    A: Array<Item = (usize, T)>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: SparseVector<A>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+impl<'a, T, A> SubAssign<&'a SparseVector<A>> for SparseVector<A>
 where
     T: Copy + Zero + Sub<T, Output = T>,
     A: Array<Item = (usize, T)>,
-    V: IntoIterator<Item = (usize, T)>,
-    <V as IntoIterator>::IntoIter: ExactSizeIterator + OrderedMapIterator<Key = usize, Val = T>,
 {
     #[inline]
-    fn sub_assign(&mut self, rhs: V) {
+    fn sub_assign(&mut self, rhs: &'a SparseVector<A>) {
+        assert_compatible(self.dim, &self.components[..], rhs.dim, &rhs.components[..]);
         self.components = {
-            let iter = rhs.into_iter();
-            let outer_join = self.iter().outer_join(iter);
+            let outer_join = self.iter().outer_join(rhs.iter());
             outer_join.filter_map(|(index, (lhs, rhs))| {
                     let value = match (lhs, rhs) {
                         (Some(l), Some(r)) => l.sub(r),
@@ -53,6 +76,115 @@ where
                 })
                 .collect()
         };
+        self.dim = self.dim.or(rhs.dim);
+    }
+}
+
+impl<T, A, B> Sub<SparseVector<A>> for dense::stack::DenseVector<B>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: SparseVector<A>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+impl<'a, T, A, B> Sub<&'a SparseVector<A>> for dense::stack::DenseVector<B>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &'a SparseVector<A>) -> Self::Output {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+impl<T, A, B> SubAssign<SparseVector<A>> for dense::stack::DenseVector<B>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: SparseVector<A>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+impl<'a, T, A, B> SubAssign<&'a SparseVector<A>> for dense::stack::DenseVector<B>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+    B: Array<Item = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: &'a SparseVector<A>) {
+        scatter_dense(self.as_mut_slice(), rhs.dim, &rhs.components[..], |lhs, rhs| *lhs -= rhs);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> Sub<SparseVector<A>> for dense::heap::DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: SparseVector<A>) -> Self::Output {
+        self.sub_assign(&rhs);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, A> Sub<&'a SparseVector<A>> for dense::heap::DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: &'a SparseVector<A>) -> Self::Output {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A> SubAssign<SparseVector<A>> for dense::heap::DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: SparseVector<A>) {
+        self.sub_assign(&rhs);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, A> SubAssign<&'a SparseVector<A>> for dense::heap::DenseVector<T>
+where
+    T: Copy + SubAssign<T>,
+    A: Array<Item = (usize, T)>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: &'a SparseVector<A>) {
+        scatter_dense(self.as_mut_slice(), rhs.dim, &rhs.components[..], |lhs, rhs| *lhs -= rhs);
     }
 }
 
@@ -94,4 +226,20 @@ mod test {
         result -= &other;
         expect!(result).to(be_equal_to(expected));
     }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn sub_dimension_mismatch() {
+        let subject = Type::from_iter(vec![(0, 0.2), (1, 0.5)]).with_dim(5).unwrap();
+        let other = Type::from_iter(vec![(1, 0.1), (2, 0.2)]).with_dim(3).unwrap();
+        let _ = subject - &other;
+    }
+
+    #[test]
+    fn sub_propagates_dim() {
+        let subject = Type::from_iter(vec![(0, 0.2), (1, 0.5)]);
+        let other = Type::from_iter(vec![(1, 0.1), (2, 0.2)]).with_dim(5).unwrap();
+        let result = subject - other;
+        expect!(result.dim()).to(be_some().value(5));
+    }
 }